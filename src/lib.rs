@@ -1,7 +1,37 @@
-use std::ops::{Add, BitXor, Div, Mul, Neg, Sub};
+use std::{
+    cmp::Ordering,
+    f64::consts::{PI, TAU},
+    fmt,
+    ops::{
+        Add, AddAssign, BitXor, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub,
+        SubAssign,
+    },
+};
 
 const EPS: f64 = 1e-7;
 
+// Into [-π, π)
+pub fn wrap_angle(a: f64) -> f64 {
+    (a + PI).rem_euclid(TAU) - PI
+}
+
+// Shortest signed angle from b to a
+pub fn angle_difference(a: f64, b: f64) -> f64 {
+    wrap_angle(a - b)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlerpError {
+    // An input is too short to have a direction
+    ZeroLength,
+    // The inputs are opposite, so no great circle is preferred
+    Antiparallel,
+    // An input has an infinite or NaN component
+    NonFinite,
+    // A rotor input is not of unit magnitude
+    NotNormalized,
+}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct Vec3 {
     e1: f64,
@@ -16,10 +46,27 @@ impl Vec3 {
         Self { e1, e2, e3 }
     }
 
+    // Panics at compile time when used in a const context with axis > 2
+    pub const fn unit(axis: usize) -> Self {
+        match axis {
+            0 => Self::new(1.0, 0.0, 0.0),
+            1 => Self::new(0.0, 1.0, 0.0),
+            2 => Self::new(0.0, 0.0, 1.0),
+            _ => panic!("Vec3::unit axis must be 0, 1 or 2"),
+        }
+    }
+
     pub const fn dot(self, rhs: Self) -> f64 {
         self.e1 * rhs.e1 + self.e2 * rhs.e2 + self.e3 * rhs.e3
     }
 
+    // Right-handed, the dual of the wedge: self ^ rhs has e23, e31, e12
+    // components equal to the x, y, z of the cross product
+    pub fn cross(self, rhs: Self) -> Self {
+        let w = self ^ rhs;
+        Self::new(w.e23, w.e31, w.e12)
+    }
+
     pub fn inverse(self) -> Self {
         debug_assert!(!self.is_zero());
         self / self.length_squared()
@@ -33,12 +80,48 @@ impl Vec3 {
         self.length_squared().sqrt()
     }
 
+    pub fn distance_squared(self, rhs: Self) -> f64 {
+        (self - rhs).length_squared()
+    }
+
+    pub fn distance(self, rhs: Self) -> f64 {
+        (self - rhs).length()
+    }
+
+    // atan2 of the wedge and dot, accurate near 0 and π unlike acos
+    pub fn angle_between(self, rhs: Self) -> f64 {
+        debug_assert!(!self.is_zero() && !rhs.is_zero());
+        let wedge = self ^ rhs;
+        wedge.dot(wedge).sqrt().atan2(self.dot(rhs))
+    }
+
+    // |self ^ rhs| relative to the lengths, so the zero vector is parallel to
+    // everything (and perpendicular too)
+    pub fn is_parallel(self, rhs: Self, tol: f64) -> bool {
+        let wedge = self ^ rhs;
+        wedge.dot(wedge).sqrt() <= tol * self.length() * rhs.length()
+    }
+
+    pub fn is_perpendicular(self, rhs: Self, tol: f64) -> bool {
+        self.dot(rhs).abs() <= tol * self.length() * rhs.length()
+    }
+
+    // For unit vectors only, and imprecise for angles near 0 and π
+    pub fn angle_between_fast(self, rhs: Self) -> f64 {
+        self.dot(rhs).clamp(-1.0, 1.0).acos()
+    }
+
     pub const fn is_close(self, rhs: Self) -> bool {
         (self.e1 - rhs.e1).abs() < EPS
             && (self.e2 - rhs.e2).abs() < EPS
             && (self.e3 - rhs.e3).abs() < EPS
     }
 
+    // Antiparallel vectors are not close, and the zero vector has no direction
+    pub fn is_close_direction(self, rhs: Self) -> bool {
+        !self.is_zero() && !rhs.is_zero() && self.normalized().is_close(rhs.normalized())
+    }
+
     pub const fn is_zero(self) -> bool {
         self.e1 == 0.0 && self.e2 == 0.0 && self.e3 == 0.0
     }
@@ -47,7 +130,381 @@ impl Vec3 {
         self / self.length()
     }
 
+    // Same as normalized, named like Rotor3::normalize
+    pub fn normalize(self) -> Self {
+        self.normalized()
+    }
+
+    pub fn normalize_or_zero(self) -> Self {
+        let length = self.length();
+        if length < EPS {
+            Self::ZERO
+        } else {
+            self / length
+        }
+    }
+
+    pub const fn is_normalized(self) -> bool {
+        (self.length_squared() - 1.0).abs() < EPS
+    }
+
+    pub fn project_onto(self, onto: Self) -> Self {
+        debug_assert!(!onto.is_zero());
+        self.dot(onto) / onto.length_squared() * onto
+    }
+
+    pub fn reject_from(self, onto: Self) -> Self {
+        self - self.project_onto(onto)
+    }
+
+    pub fn project_onto_unit(self, unit_axis: Self) -> Self {
+        debug_assert!(unit_axis.is_normalized());
+        self.dot(unit_axis) * unit_axis
+    }
+
+    // Signed length of the projection onto axis, 0 for a zero axis
+    pub fn scalar_projection(self, axis: Self) -> f64 {
+        if axis.is_zero() {
+            0.0
+        } else {
+            self.dot(axis) / axis.length()
+        }
+    }
+
+    // Side of the plane through the origin and the signed distance to it,
+    // Equal within EPS of the plane
+    pub fn classify_against_plane(self, normal: Self) -> (Ordering, f64) {
+        let distance = self.scalar_projection(normal);
+        let side = if distance.abs() < EPS {
+            Ordering::Equal
+        } else if distance > 0.0 {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        };
+        (side, distance)
+    }
+
+    // Plane through point_on_plane, a zero normal leaves self unchanged
+    pub fn project_onto_plane_at(self, point_on_plane: Self, normal: Self) -> Self {
+        if normal.is_zero() {
+            return self;
+        }
+        self - (self - point_on_plane).dot(normal) / normal.length_squared() * normal
+    }
+
+    // Unclamped, so t outside [0, 1] extrapolates along the line
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        self + t * (other - self)
+    }
+
+    // Same as lerp, for call sites that want the extrapolation spelled out
+    pub fn lerp_unclamped(self, other: Self, t: f64) -> Self {
+        self.lerp(other, t)
+    }
+
+    // Moves at most max_delta towards target without overshooting it
+    pub fn move_towards(self, target: Self, max_delta: f64) -> Self {
+        let delta = target - self;
+        let distance = delta.length();
+        if distance <= max_delta {
+            target
+        } else {
+            self + delta * (max_delta / distance)
+        }
+    }
+
+    pub fn clamp_to_ball(self, radius: f64) -> Self {
+        let length = self.length();
+        if length > radius {
+            self * (radius / length)
+        } else {
+            self
+        }
+    }
+
+    // The zero vector has no direction and is returned unchanged
+    pub fn clamp_to_sphere(self, radius: f64) -> Self {
+        if self.is_zero() {
+            self
+        } else {
+            self * (radius / self.length())
+        }
+    }
+
+    pub const fn drop_x(self) -> Self {
+        Self::new(0.0, self.e2, self.e3)
+    }
+
+    pub const fn drop_y(self) -> Self {
+        Self::new(self.e1, 0.0, self.e3)
+    }
+
+    pub const fn drop_z(self) -> Self {
+        Self::new(self.e1, self.e2, 0.0)
+    }
+
+    pub const fn onto_plane_yz(self) -> Self {
+        self.drop_x()
+    }
+
+    pub const fn onto_plane_zx(self) -> Self {
+        self.drop_y()
+    }
+
+    pub const fn onto_plane_xy(self) -> Self {
+        self.drop_z()
+    }
+
+    pub const fn mirror_xy(self) -> Self {
+        Self::new(self.e1, self.e2, -self.e3)
+    }
+
+    pub const fn mirror_yz(self) -> Self {
+        Self::new(-self.e1, self.e2, self.e3)
+    }
+
+    pub const fn mirror_zx(self) -> Self {
+        Self::new(self.e1, -self.e2, self.e3)
+    }
+
+    pub const fn max_abs(self) -> f64 {
+        self.e1.abs().max(self.e2.abs()).max(self.e3.abs())
+    }
+
+    pub const fn manhattan_length(self) -> f64 {
+        self.e1.abs() + self.e2.abs() + self.e3.abs()
+    }
+
+    pub const fn chebyshev_length(self) -> f64 {
+        self.max_abs()
+    }
+
+    pub fn manhattan_distance(self, rhs: Self) -> f64 {
+        (self - rhs).manhattan_length()
+    }
+
+    pub fn chebyshev_distance(self, rhs: Self) -> f64 {
+        (self - rhs).chebyshev_length()
+    }
+
+    // Infinity-norm normalization, the zero vector is returned unchanged
+    pub fn normalize_max(self) -> Self {
+        let max_abs = self.max_abs();
+        if max_abs == 0.0 { self } else { self / max_abs }
+    }
+
+    // Signed basis vector along the largest absolute component
+    pub fn nearest_axis(self) -> Self {
+        let (e1, e2, e3) = (self.e1.abs(), self.e2.abs(), self.e3.abs());
+        if e1 >= e2 && e1 >= e3 {
+            Self::new(self.e1.signum(), 0.0, 0.0)
+        } else if e2 >= e3 {
+            Self::new(0.0, self.e2.signum(), 0.0)
+        } else {
+            Self::new(0.0, 0.0, self.e3.signum())
+        }
+    }
+
+    // phi is measured in the xy-plane from +x
+    pub fn from_cylindrical(rho: f64, phi: f64, z: f64) -> Self {
+        let (sin, cos) = phi.sin_cos();
+        Self::new(rho * cos, rho * sin, z)
+    }
+
+    // (rho, phi, z), with phi = 0 on the z-axis
+    pub fn to_cylindrical(self) -> (f64, f64, f64) {
+        let rho = self.e1.hypot(self.e2);
+        let phi = if rho < EPS {
+            0.0
+        } else {
+            self.e2.atan2(self.e1)
+        };
+        (rho, phi, self.e3)
+    }
+
+    // Octahedral encoding of a unit vector into [-1, 1]², the lower
+    // hemisphere being folded over the diagonals
+    pub fn to_oct(self) -> (f64, f64) {
+        let sign = |x: f64| if x < 0.0 { -1.0 } else { 1.0 };
+        let n = self / self.manhattan_length();
+        if n.e3 >= 0.0 {
+            (n.e1, n.e2)
+        } else {
+            (
+                (1.0 - n.e2.abs()) * sign(n.e1),
+                (1.0 - n.e1.abs()) * sign(n.e2),
+            )
+        }
+    }
+
+    pub fn from_oct((x, y): (f64, f64)) -> Self {
+        let sign = |x: f64| if x < 0.0 { -1.0 } else { 1.0 };
+        let z = 1.0 - x.abs() - y.abs();
+        let (x, y) = if z >= 0.0 {
+            (x, y)
+        } else {
+            ((1.0 - y.abs()) * sign(x), (1.0 - x.abs()) * sign(y))
+        };
+        Self::new(x, y, z).normalized()
+    }
+
+    pub const fn to_array(self) -> [f64; 3] {
+        [self.e1, self.e2, self.e3]
+    }
+
+    // Reads the first three elements, panics if the slice is shorter
+    pub fn from_slice(slice: &[f64]) -> Self {
+        match slice {
+            [e1, e2, e3, ..] => Self::new(*e1, *e2, *e3),
+            _ => panic!("Vec3::from_slice needs 3 elements, got {}", slice.len()),
+        }
+    }
+
+    pub const fn to_homogeneous(self, w: f64) -> [f64; 4] {
+        [self.e1, self.e2, self.e3, w]
+    }
+
+    // w ≈ 0 is a direction, whose components are returned undivided
+    pub fn from_homogeneous([x, y, z, w]: [f64; 4]) -> Self {
+        let v = Self::new(x, y, z);
+        if w.abs() < EPS { v } else { v / w }
+    }
+
+    // self * rhsᵀ, unrelated to the wedge product
+    pub const fn outer_matrix(self, rhs: Self) -> [[f64; 3]; 3] {
+        let (a, b) = (self, rhs);
+        [
+            [a.e1 * b.e1, a.e1 * b.e2, a.e1 * b.e3],
+            [a.e2 * b.e1, a.e2 * b.e2, a.e2 * b.e3],
+            [a.e3 * b.e1, a.e3 * b.e2, a.e3 * b.e3],
+        ]
+    }
+
+    // Point on the great circle from self to other and its derivative in t,
+    // whose length is the arc angle. For antiparallel inputs every great
+    // circle through them is a shortest path, an arbitrary one is used.
+    pub fn slerp_with_tangent(self, other: Self, t: f64) -> (Self, Self) {
+        let (a, b) = (self.normalized(), other.normalized());
+        let wedge = a ^ b;
+        let sin = wedge.dot(wedge).sqrt();
+        if sin < EPS && a.dot(b) > 0.0 {
+            // Nearly parallel, the great circle degenerates to the chord
+            return ((a + t * (b - a)).normalized(), b - a);
+        }
+        if sin < EPS {
+            let (_, p, _) = a.stable_basis(a);
+            let (sin_t, cos_t) = (t * PI).sin_cos();
+            return (cos_t * a + sin_t * p, PI * (cos_t * p - sin_t * a));
+        }
+        let angle = sin.atan2(a.dot(b));
+        let ((sin_a, cos_a), (sin_b, cos_b)) =
+            (((1.0 - t) * angle).sin_cos(), (t * angle).sin_cos());
+        let position = (sin_a * a + sin_b * b) / sin;
+        let tangent = angle * (cos_b * b - cos_a * a) / sin;
+        (position, tangent)
+    }
+
+    // Great-circle interpolation of the directions of self and other
+    pub fn try_slerp(self, other: Self, t: f64) -> Result<Self, SlerpError> {
+        if self.length() < EPS || other.length() < EPS {
+            return Err(SlerpError::ZeroLength);
+        }
+        if self.normalized().dot(other.normalized()) < -1.0 + EPS {
+            return Err(SlerpError::Antiparallel);
+        }
+        Ok(self.slerp_with_tangent(other, t).0)
+    }
+
+    // Right-handed orthonormal basis starting with self, whose tangent is the
+    // part of reference orthogonal to self so it follows reference smoothly
+    pub fn stable_basis(self, reference: Self) -> (Self, Self, Self) {
+        let normal = self.normalized();
+        let mut tangent = reference - reference.dot(normal) * normal;
+        if tangent.length_squared() <= EPS * reference.length_squared() {
+            // reference is parallel to self, fall back to an axis that isn't
+            let axis = Self::unit(if normal.e1.abs() < 0.9 { 0 } else { 1 });
+            tangent = axis - axis.dot(normal) * normal;
+        }
+        let tangent = tangent.normalized();
+        (normal, tangent, normal.cross(tangent))
+    }
+
+    // Same as reflect_across_line
     pub fn reflected_by(self, axis: Self) -> Self {
+        self.reflected_by_unscaled(axis) / axis.length_squared()
+    }
+
+    // Mirrors self across the line through line_dir, flipping the part of self
+    // in the plane perpendicular to it (the reflected_by behavior)
+    //
+    //        self   │   result
+    //           ╲   │   ╱
+    //            ╲  │  ╱
+    //             ╲ │ ╱
+    //              ╲│╱
+    //               ╵ line_dir
+    pub fn reflect_across_line(self, line_dir: Self) -> Self {
+        self.reflected_by(line_dir)
+    }
+
+    // Across the plane equidistant from the directions of a and b, which is
+    // undefined for parallel a and b, leaving self unchanged
+    pub fn reflect_across_bisector(self, a: Self, b: Self) -> Self {
+        let normal = a.normalized() - b.normalized();
+        if normal.length_squared() < EPS {
+            return self;
+        }
+        self - 2.0 * self.dot(normal) / normal.length_squared() * normal
+    }
+
+    // GLSL reflect, mirroring self across the plane of the unit normal
+    // (unlike reflected_by, which mirrors across the axis)
+    pub fn glsl_reflect(self, normal: Self) -> Self {
+        self - 2.0 * normal.dot(self) * normal
+    }
+
+    // GLSL refract for a unit self and normal, with eta the ratio of the
+    // refractive indices. None on total internal reflection.
+    pub fn refract(self, normal: Self, eta: f64) -> Option<Self> {
+        let cos = normal.dot(self);
+        let k = 1.0 - eta * eta * (1.0 - cos * cos);
+        if k < 0.0 {
+            return None;
+        }
+        Some(eta * self - (eta * cos + k.sqrt()) * normal)
+    }
+
+    // Reflected and refracted directions, and the Schlick reflectance, which is
+    // 1 on total internal reflection
+    pub fn fresnel_split(self, normal: Self, ior_ratio: f64) -> (Self, Option<Self>, f64) {
+        let reflected = self.glsl_reflect(normal);
+        let Some(refracted) = self.refract(normal, ior_ratio) else {
+            return (reflected, None, 1.0);
+        };
+        let r0 = ((ior_ratio - 1.0) / (ior_ratio + 1.0)).powi(2);
+        // Schlick needs the angle on the less dense side
+        let cos = if ior_ratio > 1.0 {
+            -normal.dot(refracted)
+        } else {
+            -normal.dot(self)
+        };
+        (
+            reflected,
+            Some(refracted),
+            r0 + (1.0 - r0) * (1.0 - cos).powi(5),
+        )
+    }
+
+    pub fn reflect_slice(points: &mut [Self], axis: Self) {
+        let length_squared = axis.length_squared();
+        for p in points {
+            *p = p.reflected_by_unscaled(axis) / length_squared;
+        }
+    }
+
+    // reflected_by times axis.length_squared()
+    fn reflected_by_unscaled(self, axis: Self) -> Self {
         // Derived from ava⁻¹ (self * axis * self.inverse())
         // https://jacquesheunis.com/post/rotors/#reflections-with-the-geometric-product
         let (a1, a2, a3) = axis.into();
@@ -55,23 +512,110 @@ impl Vec3 {
         let p1 = a1 * a1 * v1 - a2 * a2 * v1 - a3 * a3 * v1 + 2. * a1 * a2 * v2 + 2. * a3 * a1 * v3;
         let p2 = a2 * a2 * v2 - a3 * a3 * v2 - a1 * a1 * v2 + 2. * a2 * a3 * v3 + 2. * a1 * a2 * v1;
         let p3 = a3 * a3 * v3 - a1 * a1 * v3 - a2 * a2 * v3 + 2. * a3 * a1 * v1 + 2. * a2 * a3 * v2;
-        Self::new(p1, p2, p3) / axis.length_squared()
+        Self::new(p1, p2, p3)
+    }
+
+    // Counterclockwise in the xy-plane, z is untouched
+    pub fn rotate_in_xy(self, angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(
+            cos * self.e1 - sin * self.e2,
+            sin * self.e1 + cos * self.e2,
+            self.e3,
+        )
+    }
+
+    pub fn rotate_in_yz(self, angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(
+            self.e1,
+            cos * self.e2 - sin * self.e3,
+            sin * self.e2 + cos * self.e3,
+        )
+    }
+
+    pub fn rotate_in_zx(self, angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(
+            sin * self.e3 + cos * self.e1,
+            self.e2,
+            cos * self.e3 - sin * self.e1,
+        )
     }
 
     pub fn rotated_by(self, from: Self, to: Self) -> Self {
-        let rotor = Rotor3::from_to(from, to);
-        let dr = rotor * self;
-        // rotor is normalized, so the inverse is the same as the reverse
-        let rr = rotor.reverse();
-        // DualRotor3 * Rotor3 multiplication, ignoring the e123 = 0 term
-        Self {
-            e1: dr.e1 * rr.e - dr.e2 * rr.e12 + dr.e3 * rr.e31 - dr.e123 * rr.e23,
-            e2: dr.e2 * rr.e + dr.e1 * rr.e12 - dr.e3 * rr.e23 - dr.e123 * rr.e31,
-            e3: dr.e3 * rr.e - dr.e1 * rr.e31 + dr.e2 * rr.e23 - dr.e123 * rr.e12,
+        Rotor3::from_to(from, to).rotate(self)
+    }
+
+    pub fn weighted_sum(vectors: &[Self], weights: &[f64]) -> Self {
+        debug_assert_eq!(vectors.len(), weights.len());
+        vectors
+            .iter()
+            .zip(weights)
+            .fold(Self::ZERO, |acc, (&v, &w)| acc + w * v)
+    }
+
+    // t in [0, 1] is split evenly between the segments, regardless of their
+    // lengths. Clamped, and ZERO for an empty path.
+    pub fn sample_polyline(points: &[Self], t: f64) -> Self {
+        let segments = match points {
+            [] => return Self::ZERO,
+            [only] => return *only,
+            _ => points.len() - 1,
+        };
+        let s = t.clamp(0.0, 1.0) * segments as f64;
+        let i = (s as usize).min(segments - 1);
+        let (a, b) = (points[i], points[i + 1]);
+        a + (s - i as f64) * (b - a)
+    }
+
+    // Weights of p relative to the triangle abc, from ratios of signed areas
+    pub fn barycentric(p: Self, a: Self, b: Self, c: Self) -> (f64, f64, f64) {
+        let n = (b - a) ^ (c - a);
+        let area = n.dot(n);
+        debug_assert!(area != 0.0);
+        let u = ((b - p) ^ (c - p)).dot(n) / area;
+        let v = ((c - p) ^ (a - p)).dot(n) / area;
+        (u, v, 1.0 - u - v)
+    }
+}
+
+impl Index<usize> for Vec3 {
+    type Output = f64;
+
+    fn index(&self, i: usize) -> &f64 {
+        match i {
+            0 => &self.e1,
+            1 => &self.e2,
+            2 => &self.e3,
+            _ => panic!("Vec3 index out of range: {i}"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vec3 {
+    fn index_mut(&mut self, i: usize) -> &mut f64 {
+        match i {
+            0 => &mut self.e1,
+            1 => &mut self.e2,
+            2 => &mut self.e3,
+            _ => panic!("Vec3 index out of range: {i}"),
         }
     }
 }
 
+impl From<[f64; 3]> for Vec3 {
+    fn from([e1, e2, e3]: [f64; 3]) -> Self {
+        Self::new(e1, e2, e3)
+    }
+}
+
+impl From<Vec3> for [f64; 3] {
+    fn from(v: Vec3) -> Self {
+        v.to_array()
+    }
+}
+
 impl From<Vec3> for (f64, f64, f64) {
     fn from(v: Vec3) -> Self {
         (v.e1, v.e2, v.e3)
@@ -91,6 +635,43 @@ impl BiVec3 {
     pub const fn new(e12: f64, e23: f64, e31: f64) -> Self {
         Self { e12, e23, e31 }
     }
+
+    // Dual plane e123 * axis, keeping the axis length as the magnitude
+    pub const fn from_axis(axis: Vec3) -> Self {
+        Self::new(axis.e3, axis.e1, axis.e2)
+    }
+
+    // The magnitude is the half-angle of the rotation in this plane
+    pub fn exp(self) -> Rotor3 {
+        let angle = self.dot(self).sqrt();
+        // sin(angle) / angle, with its Taylor expansion near 0
+        let sinc = if angle < EPS {
+            1.0 - angle * angle / 6.0
+        } else {
+            angle.sin() / angle
+        };
+        Rotor3::new(angle.cos(), self * sinc)
+    }
+
+    // Positive-definite, so the opposite sign of the scalar part of self * rhs
+    // (unit bivectors square to -1)
+    pub const fn dot(self, rhs: Self) -> f64 {
+        self.e12 * rhs.e12 + self.e23 * rhs.e23 + self.e31 * rhs.e31
+    }
+
+    // Angle between the two planes, taking their orientation into account
+    pub fn angle_between(self, rhs: Self) -> f64 {
+        let lengths = (self.dot(self) * rhs.dot(rhs)).sqrt();
+        debug_assert!(lengths != 0.0);
+        (self.dot(rhs) / lengths).clamp(-1.0, 1.0).acos()
+    }
+
+    // Rotates the plane of self onto the plane of other, the plane analogue
+    // of Rotor3::from_to (on the dual axes, so it fails for opposite planes)
+    pub fn rotor_between(self, other: Self) -> Rotor3 {
+        let axis = |b: Self| Vec3::new(b.e23, b.e31, b.e12);
+        Rotor3::from_to(axis(self), axis(other))
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -120,6 +701,10 @@ impl Rotor3 {
     // Any rotor with only a scalar component is an identity
     pub const IDENTITY: Self = Self::new(1.0, BiVec3::ZERO);
 
+    // Rotation angle below which interpolate uses nlerp, where it is
+    // visually indistinguishable from slerp
+    pub const NLERP_THRESHOLD: f64 = 0.01;
+
     pub const fn new(scalar: f64, bivec3: BiVec3) -> Self {
         Self {
             e: scalar,
@@ -129,69 +714,573 @@ impl Rotor3 {
         }
     }
 
-    // Doesn't work if from from ≈ -to
-    pub fn from_to(from: Vec3, to: Vec3) -> Self {
-        let from = from.normalized();
-        let to = to.normalized();
-        let halfway = (from + to).normalized();
-        halfway * from
+    // Counterclockwise about axis (right-hand rule), like from_to, identity for a zero axis
+    pub fn from_axis_angle(axis: Vec3, angle: f64) -> Self {
+        if axis.is_zero() {
+            return Self::IDENTITY;
+        }
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        Self::new(cos, BiVec3::from_axis(axis.normalized() * -sin))
     }
 
-    pub const fn reverse(self) -> Self {
-        Self {
-            e: self.e,
-            e12: -self.e12,
-            e23: -self.e23,
-            e31: -self.e31,
+    // Angle in [0, 2π], near the identity the axis is arbitrarily +x
+    pub fn to_axis_angle(self) -> (Vec3, f64) {
+        let r = self.normalize();
+        let axis = -Vec3::new(r.e23, r.e31, r.e12);
+        if axis.length() < EPS {
+            return (Vec3::unit(0), 0.0);
         }
+        (axis.normalized(), 2.0 * r.e.clamp(-1.0, 1.0).acos())
     }
 
-    // TODO: implement nlerp and slerp
-    // https://jacquesheunis.com/post/rotors/
-    // #how-do-i-smoothly--correctly-interpolate-between-two-rotors
-}
+    // Rotation vector (axis scaled by angle) to rotor. A rotor holds half the
+    // angle, so the equivalent bivector for exp is -1/2 the dual of the tangent.
+    pub fn exp_map(tangent: Vec3) -> Self {
+        (BiVec3::from_axis(tangent) * -0.5).exp()
+    }
 
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
-pub struct DualRotor3 {
-    e1: f64,
-    e2: f64,
-    e3: f64,
-    e123: f64,
-}
+    // Inverse of exp_map, -2 times the dual of ln
+    pub fn log_map(self) -> Vec3 {
+        let b = self.ln();
+        -2.0 * Vec3::new(b.e23, b.e31, b.e12)
+    }
 
-impl DualRotor3 {
-    pub const ZERO: Self = Self::new(Vec3::ZERO, TriVec3::ZERO);
+    // Same as exp_map, the rotation vector rv is the axis scaled by the angle
+    // and the Taylor fallback near zero is in BiVec3::exp
+    pub fn from_rotation_vector(rv: Vec3) -> Self {
+        Self::exp_map(rv)
+    }
 
-    pub const fn new(vec3: Vec3, trivec3: TriVec3) -> Self {
-        Self {
-            e1: vec3.e1,
-            e2: vec3.e2,
-            e3: vec3.e3,
-            e123: trivec3.e123,
-        }
+    // Same as log_map
+    pub fn to_rotation_vector(self) -> Vec3 {
+        self.log_map()
     }
-}
 
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
-pub struct MultiVec3 {
-    e: f64,
-    e1: f64,
-    e2: f64,
-    e3: f64,
-    e12: f64,
-    e23: f64,
-    e31: f64,
-    e123: f64,
-}
+    // Rotation by the angular velocity omega (axis scaled by rad/s) over dt
+    pub fn from_angular_velocity(omega: Vec3, dt: f64) -> Self {
+        Self::exp_map(omega * dt)
+    }
 
-impl MultiVec3 {
-    pub const ZERO: Self = Self::new(0.0, Vec3::ZERO, BiVec3::ZERO, TriVec3::ZERO);
+    // Inverse of from_angular_velocity. ln holds half the angle and is dual to
+    // the axis, so this is -2 times the dual of ln, divided by dt.
+    pub fn to_angular_velocity(self, dt: f64) -> Vec3 {
+        self.log_map() / dt
+    }
 
-    pub const fn new(scalar: f64, vec3: Vec3, bivec3: BiVec3, trivec3: TriVec3) -> Self {
-        Self {
-            e: scalar,
-            e1: vec3.e1,
-            e2: vec3.e2,
+    // Inverse of BiVec3::exp for the normalized rotor
+    pub fn ln(self) -> BiVec3 {
+        let r = self.normalize();
+        let b = BiVec3::new(r.e12, r.e23, r.e31);
+        let sin = b.dot(b).sqrt();
+        if sin < EPS && r.e > 0.0 {
+            // Near the identity, angle / sin(angle) ≈ 1 / cos(angle)
+            return b * (1.0 / r.e);
+        }
+        if sin == 0.0 {
+            // -IDENTITY, a half-angle of π in any plane
+            return BiVec3::new(PI, 0.0, 0.0);
+        }
+        b * (sin.atan2(r.e) / sin)
+    }
+
+    // self multiplied by itself n times, renormalized once at the end, to
+    // reproduce the rounding of repeated composition (unlike powf)
+    pub fn repeated(self, n: usize) -> Self {
+        let mut r = Self::IDENTITY;
+        for _ in 0..n {
+            r = r * self;
+        }
+        r.normalize()
+    }
+
+    // Scales the rotation angle by t
+    pub fn powf(self, t: f64) -> Self {
+        (t * self.ln()).exp()
+    }
+
+    // Layers weight times delta on top of self, for additive animation
+    pub fn apply_additive(self, delta: Self, weight: f64) -> Self {
+        delta.powf(weight) * self
+    }
+
+    // m[row][col], the columns are the rotated basis vectors so that
+    // m * v (v as a column) rotates v
+    pub fn to_matrix(self) -> [[f64; 3]; 3] {
+        let r = self.normalize();
+        let [c0, c1, c2] = [0, 1, 2].map(|i| r.rotate(Vec3::unit(i)));
+        [
+            [c0.e1, c1.e1, c2.e1],
+            [c0.e2, c1.e2, c2.e2],
+            [c0.e3, c1.e3, c2.e3],
+        ]
+    }
+
+    // to_matrix flattened row by row, or column by column if column_major
+    pub fn to_matrix_array(self, column_major: bool) -> [f64; 9] {
+        let m = self.to_matrix();
+        std::array::from_fn(|i| {
+            let (major, minor) = (i / 3, i % 3);
+            if column_major {
+                m[minor][major]
+            } else {
+                m[major][minor]
+            }
+        })
+    }
+
+    // Inverse of to_matrix, m must be a rotation matrix. Follows the
+    // quaternion algorithm, branching on the largest diagonal term to avoid
+    // cancellation.
+    pub fn from_matrix(m: [[f64; 3]; 3]) -> Self {
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let (w, x, y, z) = if trace > 0.0 {
+            let s = 2.0 * (1.0 + trace).sqrt();
+            (
+                s / 4.0,
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+            )
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = 2.0 * (1.0 + m[0][0] - m[1][1] - m[2][2]).sqrt();
+            (
+                (m[2][1] - m[1][2]) / s,
+                s / 4.0,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+            )
+        } else if m[1][1] > m[2][2] {
+            let s = 2.0 * (1.0 + m[1][1] - m[0][0] - m[2][2]).sqrt();
+            (
+                (m[0][2] - m[2][0]) / s,
+                (m[0][1] + m[1][0]) / s,
+                s / 4.0,
+                (m[1][2] + m[2][1]) / s,
+            )
+        } else {
+            let s = 2.0 * (1.0 + m[2][2] - m[0][0] - m[1][1]).sqrt();
+            (
+                (m[1][0] - m[0][1]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                s / 4.0,
+            )
+        };
+        Self::from_quaternion(w, x, y, z)
+    }
+
+    // from_matrix for a nearly orthonormal m: the columns are Gram-Schmidt
+    // orthonormalized in order, and the last one negated if m is a reflection
+    pub fn from_matrix_orthonormalized(m: [[f64; 3]; 3]) -> Self {
+        let [c0, c1, c2] = [0, 1, 2].map(|j| Vec3::new(m[0][j], m[1][j], m[2][j]));
+        let c0 = c0.normalized();
+        let c1 = c1.reject_from(c0).normalized();
+        let c2 = c2.reject_from(c0).reject_from(c1).normalized();
+        let c2 = if c0.cross(c1).dot(c2) < 0.0 { -c2 } else { c2 };
+        Self::from_matrix([
+            [c0.e1, c1.e1, c2.e1],
+            [c0.e2, c1.e2, c2.e2],
+            [c0.e3, c1.e3, c2.e3],
+        ])
+    }
+
+    // Maps the canonical frame (right +x, up +y, forward +z) onto forward and
+    // up. forward is kept exactly and up only has its part orthogonal to
+    // forward kept, falling back to another axis if they are parallel.
+    pub fn from_forward_up(forward: Vec3, up: Vec3) -> Self {
+        let (forward, up, _) = forward.stable_basis(up);
+        let right = up.cross(forward);
+        Self::from_matrix([
+            [right.e1, up.e1, forward.e1],
+            [right.e2, up.e2, forward.e2],
+            [right.e3, up.e3, forward.e3],
+        ])
+    }
+
+    // twist in self = swing * twist, where twist rotates about axis and swing
+    // about an axis orthogonal to it. It is the bivector part projected onto
+    // the plane dual to axis, renormalized.
+    // IDENTITY if self is a half turn about an axis orthogonal to axis.
+    pub fn twist(self, axis: Vec3) -> Self {
+        let plane = BiVec3::from_axis(axis.normalized());
+        let b = BiVec3::new(self.e12, self.e23, self.e31);
+        Self::new(self.e, b.dot(plane) * plane).normalize_or_identity()
+    }
+
+    // Signed angle of the twist about axis, counterclockwise like
+    // from_axis_angle, in [-π, π]
+    pub fn twist_angle(self, axis: Vec3) -> f64 {
+        let twist = self.twist(axis);
+        let plane = BiVec3::from_axis(axis.normalized());
+        let b = BiVec3::new(twist.e12, twist.e23, twist.e31);
+        wrap_angle(2.0 * (-b.dot(plane)).atan2(twist.e))
+    }
+
+    // Twist angle about axis of the rotation taking unit rotor a to b
+    pub fn relative_twist(a: Self, b: Self, axis: Vec3) -> f64 {
+        (b * a.reverse()).twist_angle(axis)
+    }
+
+    // Twist about axis with its angle clamped to [min_angle, max_angle],
+    // discarding the swing
+    pub fn constrain_to_hinge(self, axis: Vec3, min_angle: f64, max_angle: f64) -> Self {
+        let angle = self.twist_angle(axis).clamp(min_angle, max_angle);
+        Self::from_axis_angle(axis, angle)
+    }
+
+    // self with its twist about forward_axis removed, so that world_up stays
+    // in the vertical plane through the rotated forward axis. Assumes the
+    // identity is level, i.e. world_up is orthogonal to forward_axis.
+    pub fn remove_roll(self, forward_axis: Vec3, world_up: Vec3) -> Self {
+        let forward = self.rotate(forward_axis);
+        Self::from_forward_up(forward, world_up)
+            * Self::from_forward_up(forward_axis, world_up).reverse()
+    }
+
+    // The imaginary parts are the negated duals of the bivector:
+    // e23 = -x, e31 = -y, e12 = -z
+    pub const fn from_quaternion(w: f64, x: f64, y: f64, z: f64) -> Self {
+        Self::new(w, BiVec3::new(-z, -x, -y))
+    }
+
+    // (w, x, y, z)
+    pub const fn to_quaternion(self) -> (f64, f64, f64, f64) {
+        (self.e, -self.e23, -self.e31, -self.e12)
+    }
+
+    // Intrinsic Z-Y-X (yaw about z, then pitch about the new y, then roll about
+    // the new x), i.e. roll is applied first in world axes. Right-handed,
+    // positive angles are counterclockwise looking down each axis.
+    pub fn from_euler(yaw: f64, pitch: f64, roll: f64) -> Self {
+        Self::from_axis_angle(Vec3::unit(2), yaw)
+            * Self::from_axis_angle(Vec3::unit(1), pitch)
+            * Self::from_axis_angle(Vec3::unit(0), roll)
+    }
+
+    // (yaw, pitch, roll) in the from_euler convention, with yaw and roll in
+    // [-π, π] and pitch in [-π/2, π/2]. At pitch ±π/2 only yaw ∓ roll is
+    // defined, so roll is set to 0 and yaw carries the whole rotation.
+    pub fn to_euler(self) -> (f64, f64, f64) {
+        let m = self.to_matrix();
+        let cos_pitch = m[0][0].hypot(m[1][0]);
+        let pitch = (-m[2][0]).atan2(cos_pitch);
+        if cos_pitch > EPS {
+            (m[1][0].atan2(m[0][0]), pitch, m[2][1].atan2(m[2][2]))
+        } else {
+            ((-m[0][1]).atan2(m[1][1]), pitch, 0.0)
+        }
+    }
+
+    // Doesn't work if from from ≈ -to
+    pub fn from_to(from: Vec3, to: Vec3) -> Self {
+        let from = from.normalized();
+        let to = to.normalized();
+        let halfway = (from + to).normalized();
+        // Unit in exact arithmetic, renormalized so chained rotors don't drift
+        (halfway * from).normalize()
+    }
+
+    // fraction of the way of from_to, along the same arc
+    pub fn from_to_partial(from: Vec3, to: Vec3, fraction: f64) -> Self {
+        Self::from_to(from, to).powf(fraction)
+    }
+
+    // [e, e12, e23, e31] downcast for GPU buffers, keeping only ~7 significant digits
+    pub const fn to_f32_array(self) -> [f32; 4] {
+        [
+            self.e as f32,
+            self.e12 as f32,
+            self.e23 as f32,
+            self.e31 as f32,
+        ]
+    }
+
+    pub const fn from_f32_array([e, e12, e23, e31]: [f32; 4]) -> Self {
+        Self::new(e as f64, BiVec3::new(e12 as f64, e23 as f64, e31 as f64))
+    }
+
+    // Rotor3 only has even grades, so the nearest unit rotor is the normalized one
+    pub fn project_to_so3(self) -> Self {
+        self.normalize()
+    }
+
+    // Rotors as points on the 4D unit sphere
+    const fn dot(self, rhs: Self) -> f64 {
+        self.e * rhs.e + self.e12 * rhs.e12 + self.e23 * rhs.e23 + self.e31 * rhs.e31
+    }
+
+    pub const fn magnitude_squared(self) -> f64 {
+        self.dot(self)
+    }
+
+    pub fn magnitude(self) -> f64 {
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn normalize(self) -> Self {
+        let magnitude = self.magnitude();
+        Self {
+            e: self.e / magnitude,
+            e12: self.e12 / magnitude,
+            e23: self.e23 / magnitude,
+            e31: self.e31 / magnitude,
+        }
+    }
+
+    pub fn normalize_or_identity(self) -> Self {
+        if self.magnitude() < EPS {
+            Self::IDENTITY
+        } else {
+            self.normalize()
+        }
+    }
+
+    // Componentwise lerp towards whichever of ±other is nearer
+    pub fn blend_linear(self, other: Self, t: f64, renormalize: bool) -> Self {
+        let other = if self.dot(other) < 0.0 { -other } else { other };
+        let blend = Self {
+            e: self.e + t * (other.e - self.e),
+            e12: self.e12 + t * (other.e12 - self.e12),
+            e23: self.e23 + t * (other.e23 - self.e23),
+            e31: self.e31 + t * (other.e31 - self.e31),
+        };
+        if renormalize {
+            blend.normalize()
+        } else {
+            blend
+        }
+    }
+
+    pub const fn is_close(self, rhs: Self) -> bool {
+        (self.e - rhs.e).abs() < EPS
+            && (self.e12 - rhs.e12).abs() < EPS
+            && (self.e23 - rhs.e23).abs() < EPS
+            && (self.e31 - rhs.e31).abs() < EPS
+    }
+
+    // r and -r encode the same rotation (double cover)
+    pub fn represents_same_rotation(self, other: Self) -> bool {
+        self.is_close_rotation(other, EPS)
+    }
+
+    // Compares against both other and -other rather than sign-normalizing
+    // each side, which is unstable for rotations near 180° (e ≈ 0)
+    pub fn is_close_rotation(self, other: Self, eps: f64) -> bool {
+        let close = |a: Self, b: Self| {
+            (a.e - b.e).abs() < eps
+                && (a.e12 - b.e12).abs() < eps
+                && (a.e23 - b.e23).abs() < eps
+                && (a.e31 - b.e31).abs() < eps
+        };
+        close(self, other) || close(self, -other)
+    }
+
+    pub const fn reverse(self) -> Self {
+        Self {
+            e: self.e,
+            e12: -self.e12,
+            e23: -self.e23,
+            e31: -self.e31,
+        }
+    }
+
+    // Assumes a unit rotor, whose inverse is the reverse
+    pub fn rotate(self, v: Vec3) -> Vec3 {
+        self.sandwich(v, self.reverse())
+    }
+
+    pub fn rotate_vec(self, points: &[Vec3]) -> Vec<Vec3> {
+        let reverse = self.reverse();
+        points.iter().map(|&p| self.sandwich(p, reverse)).collect()
+    }
+
+    // Into the local frame of a unit rotor, whose inverse is the reverse
+    pub fn unrotate_slice(self, points: &mut [Vec3]) {
+        let reverse = self.reverse();
+        for p in points {
+            *p = reverse.sandwich(*p, self);
+        }
+    }
+
+    fn sandwich(self, v: Vec3, reverse: Self) -> Vec3 {
+        let (dr, rr) = (self * v, reverse);
+        // DualRotor3 * Rotor3 multiplication, ignoring the e123 = 0 term
+        Vec3 {
+            e1: dr.e1 * rr.e - dr.e2 * rr.e12 + dr.e3 * rr.e31 - dr.e123 * rr.e23,
+            e2: dr.e2 * rr.e + dr.e1 * rr.e12 - dr.e3 * rr.e23 - dr.e123 * rr.e31,
+            e3: dr.e3 * rr.e - dr.e1 * rr.e31 + dr.e2 * rr.e23 - dr.e123 * rr.e12,
+        }
+    }
+
+    // Along the shortest great circle of the 4D unit sphere
+    // https://jacquesheunis.com/post/rotors/
+    // #how-do-i-smoothly--correctly-interpolate-between-two-rotors
+    pub fn slerp(self, other: Self, t: f64) -> Self {
+        let dot = self.dot(other);
+        let (other, dot) = if dot < 0.0 {
+            (-other, -dot)
+        } else {
+            (other, dot)
+        };
+        if dot > 1.0 - EPS {
+            // Nearly parallel, sin(angle) ≈ 0
+            return self.blend_linear(other, t, true);
+        }
+        let angle = dot.acos();
+        let sin = angle.sin();
+        let a = ((1.0 - t) * angle).sin() / sin;
+        let b = (t * angle).sin() / sin;
+        Self {
+            e: a * self.e + b * other.e,
+            e12: a * self.e12 + b * other.e12,
+            e23: a * self.e23 + b * other.e23,
+            e31: a * self.e31 + b * other.e31,
+        }
+    }
+
+    // Critically damped step towards target, the rotor version of the usual
+    // SmoothDamp recurrence run on the bivector from self to target.
+    // velocity is that bivector's rate of change, kept between calls.
+    pub fn smooth_damp(
+        self,
+        target: Self,
+        velocity: &mut BiVec3,
+        smooth_time: f64,
+        dt: f64,
+    ) -> Self {
+        let target = if self.dot(target) < 0.0 {
+            -target
+        } else {
+            target
+        };
+        let omega = 2.0 / smooth_time;
+        let x = omega * dt;
+        // Approximates exp(-x)
+        let decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+        let offset = (target * self.reverse()).ln();
+        let change = (*velocity - omega * offset) * dt;
+        *velocity = (*velocity - omega * change) * decay;
+        (offset + (change - offset) * decay).exp() * self
+    }
+
+    // d/dt of slerp for unit rotors. slerp is self * (self~ * other)^t, so this
+    // is slerp * ln(self~ * other): a rotor-valued derivative (not a unit
+    // rotor), the slerp point times a bivector in its tangent space.
+    pub fn slerp_derivative(self, other: Self, t: f64) -> Self {
+        let other = if self.dot(other) < 0.0 { -other } else { other };
+        self.slerp(other, t) * Self::new(0.0, (self.reverse() * other).ln())
+    }
+
+    // slerp for unit rotors, rejecting inputs it would turn into garbage
+    pub fn try_slerp(self, other: Self, t: f64) -> Result<Self, SlerpError> {
+        let finite = |r: Self| [r.e, r.e12, r.e23, r.e31].iter().all(|c| c.is_finite());
+        if !finite(self) || !finite(other) || !t.is_finite() {
+            return Err(SlerpError::NonFinite);
+        }
+        let normalized = |r: Self| (r.magnitude_squared() - 1.0).abs() < EPS;
+        if !normalized(self) || !normalized(other) {
+            return Err(SlerpError::NotNormalized);
+        }
+        Ok(self.slerp(other, t))
+    }
+
+    // slerp, and whether it negated other to take the shortest path
+    pub fn slerp_with_flip_info(self, other: Self, t: f64) -> (Self, bool) {
+        (self.slerp(other, t), self.dot(other) < 0.0)
+    }
+
+    // slerp at ease(t), ease should map [0, 1] onto [0, 1] to keep the
+    // endpoints
+    pub fn slerp_eased<F: Fn(f64) -> f64>(self, other: Self, t: f64, ease: F) -> Self {
+        self.slerp(other, ease(t))
+    }
+
+    // Key i is at t = i, t is clamped to the keys. IDENTITY for no keys.
+    pub fn piecewise_slerp(keys: &[Self], t: f64) -> Self {
+        let segments = match keys {
+            [] => return Self::IDENTITY,
+            [only] => return *only,
+            _ => keys.len() - 1,
+        };
+        let s = t.clamp(0.0, segments as f64);
+        let i = (s as usize).min(segments - 1);
+        keys[i].slerp(keys[i + 1], s - i as f64)
+    }
+
+    pub fn interpolate(self, other: Self, t: f64) -> Self {
+        let angle = 2.0 * self.dot(other).abs().min(1.0).acos();
+        if angle < Self::NLERP_THRESHOLD {
+            self.nlerp(other, t)
+        } else {
+            self.slerp(other, t)
+        }
+    }
+
+    // Weights u, v and 1 - u - v for a, b and c, blended like nlerp: the
+    // normalized weighted sum after aligning b and c to the hemisphere of a.
+    // The corners are exact and the centroid is the normalized mean, but the
+    // angular rate is not uniform and rotors more than 180° apart are
+    // ambiguous.
+    pub fn barycentric_blend(a: Self, b: Self, c: Self, u: f64, v: f64) -> Self {
+        let align = |r: Self| if a.dot(r) < 0.0 { -r } else { r };
+        let (b, c, w) = (align(b), align(c), 1.0 - u - v);
+        Self {
+            e: u * a.e + v * b.e + w * c.e,
+            e12: u * a.e12 + v * b.e12 + w * c.e12,
+            e23: u * a.e23 + v * b.e23 + w * c.e23,
+            e31: u * a.e31 + v * b.e31 + w * c.e31,
+        }
+        .normalize()
+    }
+
+    // Cheaper than slerp with the same endpoints and path, but the angular
+    // velocity isn't constant, speeding up towards t = 0.5
+    pub fn nlerp(self, other: Self, t: f64) -> Self {
+        self.blend_linear(other, t, true)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DualRotor3 {
+    e1: f64,
+    e2: f64,
+    e3: f64,
+    e123: f64,
+}
+
+impl DualRotor3 {
+    pub const ZERO: Self = Self::new(Vec3::ZERO, TriVec3::ZERO);
+
+    pub const fn new(vec3: Vec3, trivec3: TriVec3) -> Self {
+        Self {
+            e1: vec3.e1,
+            e2: vec3.e2,
+            e3: vec3.e3,
+            e123: trivec3.e123,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MultiVec3 {
+    e: f64,
+    e1: f64,
+    e2: f64,
+    e3: f64,
+    e12: f64,
+    e23: f64,
+    e31: f64,
+    e123: f64,
+}
+
+impl MultiVec3 {
+    pub const ZERO: Self = Self::new(0.0, Vec3::ZERO, BiVec3::ZERO, TriVec3::ZERO);
+
+    pub const fn new(scalar: f64, vec3: Vec3, bivec3: BiVec3, trivec3: TriVec3) -> Self {
+        Self {
+            e: scalar,
+            e1: vec3.e1,
+            e2: vec3.e2,
             e3: vec3.e3,
             e12: bivec3.e12,
             e23: bivec3.e23,
@@ -199,6 +1288,99 @@ impl MultiVec3 {
             e123: trivec3.e123,
         }
     }
+
+    pub const fn from_scalar_and_vector(scalar: f64, vec3: Vec3) -> Self {
+        Self::new(scalar, vec3, BiVec3::ZERO, TriVec3::ZERO)
+    }
+
+    pub const fn from_scalar_and_bivector(scalar: f64, bivec3: BiVec3) -> Self {
+        Self::new(scalar, Vec3::ZERO, bivec3, TriVec3::ZERO)
+    }
+
+    pub const fn from_vector_and_bivector(vec3: Vec3, bivec3: BiVec3) -> Self {
+        Self::new(0.0, vec3, bivec3, TriVec3::ZERO)
+    }
+
+    pub const fn from_rotor(r: Rotor3) -> Self {
+        Self::from_scalar_and_bivector(r.e, BiVec3::new(r.e12, r.e23, r.e31))
+    }
+
+    pub const fn from_dual_rotor(d: DualRotor3) -> Self {
+        Self::new(
+            0.0,
+            Vec3::new(d.e1, d.e2, d.e3),
+            BiVec3::ZERO,
+            TriVec3::new(d.e123),
+        )
+    }
+
+    pub const fn reverse(self) -> Self {
+        Self {
+            e12: -self.e12,
+            e23: -self.e23,
+            e31: -self.e31,
+            e123: -self.e123,
+            ..self
+        }
+    }
+
+    // versor * self * versor.reverse(), a reflection for an odd unit versor
+    // and a rotation for an even one
+    pub fn sandwich(self, versor: Self) -> Self {
+        versor * self * versor.reverse()
+    }
+
+    // Products of vectors have a single parity and self * self.reverse() is
+    // a scalar. The parity check is needed for e.g. 1 + e123, whose product
+    // with its reverse is the scalar 2.
+    pub fn is_versor(self, tol: f64) -> bool {
+        let [_, rest @ ..] = (self * self.reverse()).to_blades();
+        (self.is_even(tol) || self.is_odd(tol)) && rest.iter().all(|b| b.abs() < tol)
+    }
+
+    pub fn normalized_versor(self) -> Self {
+        let norm = (self * self.reverse()).e.sqrt();
+        Self::from_blades(self.to_blades().map(|b| b / norm))
+    }
+
+    // Drops grades 1 and 3 and normalizes the even part
+    pub fn project_to_rotor(self) -> Rotor3 {
+        Rotor3::new(self.e, BiVec3::new(self.e12, self.e23, self.e31)).normalize()
+    }
+
+    // Blades ordered [e, e1, e2, e3, e12, e23, e31, e123]
+    pub const fn from_blades(b: [f64; 8]) -> Self {
+        Self {
+            e: b[0],
+            e1: b[1],
+            e2: b[2],
+            e3: b[3],
+            e12: b[4],
+            e23: b[5],
+            e31: b[6],
+            e123: b[7],
+        }
+    }
+
+    pub const fn to_blades(self) -> [f64; 8] {
+        [
+            self.e, self.e1, self.e2, self.e3, self.e12, self.e23, self.e31, self.e123,
+        ]
+    }
+
+    pub const fn blade(self, i: usize) -> f64 {
+        self.to_blades()[i]
+    }
+
+    // Only grades 0 and 2 above tol, like a rotor
+    pub const fn is_even(self, tol: f64) -> bool {
+        self.e1.abs() < tol && self.e2.abs() < tol && self.e3.abs() < tol && self.e123.abs() < tol
+    }
+
+    // Only grades 1 and 3 above tol, like a vector
+    pub const fn is_odd(self, tol: f64) -> bool {
+        self.e.abs() < tol && self.e12.abs() < tol && self.e23.abs() < tol && self.e31.abs() < tol
+    }
 }
 
 impl BitXor for Vec3 {
@@ -221,6 +1403,51 @@ impl Neg for Vec3 {
     }
 }
 
+impl Neg for Rotor3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            e: -self.e,
+            e12: -self.e12,
+            e23: -self.e23,
+            e31: -self.e31,
+        }
+    }
+}
+
+impl Add<BiVec3> for BiVec3 {
+    type Output = BiVec3;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::Output::new(self.e12 + rhs.e12, self.e23 + rhs.e23, self.e31 + rhs.e31)
+    }
+}
+
+impl Sub<BiVec3> for BiVec3 {
+    type Output = BiVec3;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::Output::new(self.e12 - rhs.e12, self.e23 - rhs.e23, self.e31 - rhs.e31)
+    }
+}
+
+impl Mul<f64> for BiVec3 {
+    type Output = BiVec3;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::Output::new(self.e12 * rhs, self.e23 * rhs, self.e31 * rhs)
+    }
+}
+
+impl Mul<BiVec3> for f64 {
+    type Output = BiVec3;
+
+    fn mul(self, rhs: BiVec3) -> Self::Output {
+        rhs * self
+    }
+}
+
 impl Mul<f64> for Vec3 {
     type Output = Vec3;
 
@@ -261,9 +1488,34 @@ impl Sub<Vec3> for Vec3 {
     }
 }
 
-impl Mul<Vec3> for Vec3 {
+impl AddAssign<Vec3> for Vec3 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<Vec3> for Vec3 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign<f64> for Vec3 {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<f64> for Vec3 {
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs;
+    }
+}
+
+impl Mul<Vec3> for Vec3 {
     type Output = Rotor3;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn mul(self, rhs: Self) -> Self::Output {
         Self::Output::new(self.dot(rhs), self ^ rhs)
     }
@@ -295,6 +1547,30 @@ impl Mul<Rotor3> for Rotor3 {
     }
 }
 
+impl Mul<&Rotor3> for Rotor3 {
+    type Output = Rotor3;
+
+    fn mul(self, rhs: &Rotor3) -> Self::Output {
+        self * *rhs
+    }
+}
+
+impl Mul<Rotor3> for &Rotor3 {
+    type Output = Rotor3;
+
+    fn mul(self, rhs: Rotor3) -> Self::Output {
+        *self * rhs
+    }
+}
+
+impl Mul<&Rotor3> for &Rotor3 {
+    type Output = Rotor3;
+
+    fn mul(self, rhs: &Rotor3) -> Self::Output {
+        *self * *rhs
+    }
+}
+
 impl Mul<Vec3> for DualRotor3 {
     type Output = Rotor3;
 
@@ -400,6 +1676,102 @@ impl Mul<Vec3> for MultiVec3 {
     }
 }
 
+// Sum of the nonzero terms like "0.5 - 2 e12", or "0" if there are none.
+// With a precision, terms that round to zero are dropped too. Width, fill
+// and alignment apply to the whole sum, left-aligned by default like str.
+fn fmt_terms(f: &mut fmt::Formatter, terms: &[(f64, &str)]) -> fmt::Result {
+    let mut sum = String::new();
+    for &(coefficient, blade) in terms {
+        let magnitude = match f.precision() {
+            Some(precision) => format!("{:.precision$}", coefficient.abs()),
+            None => coefficient.abs().to_string(),
+        };
+        if magnitude.chars().all(|c| c == '0' || c == '.') {
+            continue;
+        }
+        match (sum.is_empty(), coefficient < 0.0) {
+            (true, true) => sum.push('-'),
+            (true, false) => {}
+            (false, true) => sum.push_str(" - "),
+            (false, false) => sum.push_str(" + "),
+        }
+        sum.push_str(&magnitude);
+        if !blade.is_empty() {
+            sum.push(' ');
+            sum.push_str(blade);
+        }
+    }
+    if sum.is_empty() {
+        sum.push('0');
+    }
+
+    let padding = f.width().unwrap_or(0).saturating_sub(sum.chars().count());
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill().to_string();
+    write!(f, "{}{sum}{}", fill.repeat(before), fill.repeat(after))
+}
+
+impl fmt::Display for Vec3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_terms(f, &[(self.e1, "e1"), (self.e2, "e2"), (self.e3, "e3")])
+    }
+}
+
+impl fmt::Display for BiVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_terms(
+            f,
+            &[(self.e12, "e12"), (self.e23, "e23"), (self.e31, "e31")],
+        )
+    }
+}
+
+impl fmt::Display for TriVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_terms(f, &[(self.e123, "e123")])
+    }
+}
+
+impl fmt::Display for Rotor3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_terms(
+            f,
+            &[
+                (self.e, ""),
+                (self.e12, "e12"),
+                (self.e23, "e23"),
+                (self.e31, "e31"),
+            ],
+        )
+    }
+}
+
+impl fmt::Display for DualRotor3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_terms(
+            f,
+            &[
+                (self.e1, "e1"),
+                (self.e2, "e2"),
+                (self.e3, "e3"),
+                (self.e123, "e123"),
+            ],
+        )
+    }
+}
+
+impl fmt::Display for MultiVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let blades = ["", "e1", "e2", "e3", "e12", "e23", "e31", "e123"];
+        let terms = self.to_blades().into_iter().zip(blades);
+        fmt_terms(f, &terms.collect::<Vec<_>>())
+    }
+}
+
 impl TryFrom<DualRotor3> for Vec3 {
     type Error = ();
 
@@ -431,7 +1803,7 @@ impl TryFrom<MultiVec3> for Vec3 {
 
 #[cfg(test)]
 mod tests {
-    use {super::*, std::f64::consts::TAU};
+    use {super::*, std::f64::consts::FRAC_PI_2};
 
     #[test]
     fn wedge_product() {
@@ -492,4 +1864,1633 @@ mod tests {
 
         assert!(res.is_close(c));
     }
+
+    #[test]
+    fn barycentric() {
+        let a = Vec3::new(1.0, 0.0, 0.0);
+        let b = Vec3::new(0.0, 2.0, 0.0);
+        let c = Vec3::new(0.0, 0.0, 3.0);
+        let close = |(u, v, w): (f64, f64, f64), (x, y, z): (f64, f64, f64)| {
+            Vec3::new(u, v, w).is_close(Vec3::new(x, y, z))
+        };
+
+        assert!(close(Vec3::barycentric(a, a, b, c), (1.0, 0.0, 0.0)));
+        assert!(close(Vec3::barycentric(b, a, b, c), (0.0, 1.0, 0.0)));
+        assert!(close(Vec3::barycentric(c, a, b, c), (0.0, 0.0, 1.0)));
+
+        let third = 1.0 / 3.0;
+        let centroid = (a + b + c) / 3.0;
+        assert!(close(
+            Vec3::barycentric(centroid, a, b, c),
+            (third, third, third)
+        ));
+    }
+
+    #[test]
+    fn normalize_max() {
+        assert_eq!(Vec3::new(2.0, -4.0, 1.0).max_abs(), 4.0);
+        assert_eq!(
+            Vec3::new(2.0, -4.0, 1.0).normalize_max(),
+            Vec3::new(0.5, -1.0, 0.25)
+        );
+        assert_eq!(Vec3::ZERO.normalize_max(), Vec3::ZERO);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn rotor_reference_mul() {
+        let a = Rotor3::from_to(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 1.0));
+        let b = Rotor3::from_to(Vec3::new(0.0, 0.0, 1.0), Vec3::new(1.0, 2.0, 3.0));
+
+        assert_eq!(&a * &b, a * b);
+        assert_eq!(&a * b, a * b);
+        assert_eq!(a * &b, a * b);
+    }
+
+    #[test]
+    fn rotate_vec() {
+        let from = Vec3::new(1.0, 2.0, 0.0);
+        let to = Vec3::new(-1.0, 0.5, 3.0);
+        let rotor = Rotor3::from_to(from, to);
+        let points = [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, -2.0, 1.0),
+            Vec3::new(3.0, 4.0, 5.0),
+        ];
+
+        let rotated = rotor.rotate_vec(&points);
+
+        assert_eq!(rotated.len(), points.len());
+        for (p, r) in points.iter().zip(&rotated) {
+            assert!(r.is_close(p.rotated_by(from, to)));
+        }
+        assert!(rotor.rotate_vec(&[]).is_empty());
+    }
+
+    #[test]
+    fn bivector_angle() {
+        let e12 = BiVec3::new(1.0, 0.0, 0.0);
+        let e23 = BiVec3::new(0.0, 2.0, 0.0);
+
+        assert_eq!(e12.dot(e23), 0.0);
+        assert_eq!(
+            BiVec3::new(1.0, 2.0, 3.0).dot(BiVec3::new(4.0, 5.0, 6.0)),
+            32.0
+        );
+        assert!((e12.angle_between(e23) - FRAC_PI_2).abs() < EPS);
+        assert!((e12.angle_between(BiVec3::new(-3.0, 0.0, 0.0)) - PI).abs() < EPS);
+    }
+
+    #[test]
+    fn unit() {
+        const BASIS: [Vec3; 3] = [Vec3::unit(0), Vec3::unit(1), Vec3::unit(2)];
+
+        assert_eq!(Vec3::unit(1), Vec3::new(0.0, 1.0, 0.0));
+        assert_eq!(BASIS[0] + BASIS[1] + BASIS[2], Vec3::new(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn unit_out_of_range() {
+        Vec3::unit(3);
+    }
+
+    #[test]
+    fn nearest_axis() {
+        assert_eq!(Vec3::new(0.1, -0.9, 0.2).nearest_axis(), -Vec3::unit(1));
+        assert_eq!(Vec3::new(3.0, 1.0, -2.0).nearest_axis(), Vec3::unit(0));
+        assert_eq!(Vec3::new(0.0, 0.5, -0.7).nearest_axis(), -Vec3::unit(2));
+    }
+
+    #[test]
+    fn multivector_parity() {
+        let r = Rotor3::from_to(Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0));
+        let even = MultiVec3::from_rotor(r);
+        let odd = MultiVec3::from_scalar_and_vector(0.0, Vec3::new(1.0, 2.0, 3.0));
+
+        assert!(even.is_even(EPS) && !even.is_odd(EPS));
+        assert!(odd.is_odd(EPS) && !odd.is_even(EPS));
+        assert!((even * odd).is_odd(EPS));
+        assert!((odd * odd).is_even(EPS));
+    }
+
+    #[test]
+    fn from_to_is_unit() {
+        let pairs = [
+            (Vec3::new(1.0, 2.0, 3.0), Vec3::new(-4.0, 0.5, 2.0)),
+            (Vec3::new(1e-3, 0.0, 7.0), Vec3::new(0.0, 9e5, -1.0)),
+            (Vec3::new(0.3, -0.1, 0.2), Vec3::new(0.3, -0.1, 0.2)),
+        ];
+
+        for (from, to) in pairs {
+            let norm = Rotor3::from_to(from, to).magnitude();
+            assert!((norm - 1.0).abs() < EPS);
+        }
+    }
+
+    #[test]
+    fn from_to_partial() {
+        let (from, to) = (Vec3::new(1.0, 0.0, 1.0), Vec3::new(0.0, 2.0, 0.0));
+
+        assert!(Rotor3::from_to_partial(from, to, 0.0).is_close(Rotor3::IDENTITY));
+        let full = Rotor3::from_to_partial(from, to, 1.0);
+        assert!(full.rotate(from.normalized()).is_close(to.normalized()));
+        let half = Rotor3::from_to_partial(from, to, 0.5);
+        assert!(half.is_close(Rotor3::from_axis_angle(from.cross(to), FRAC_PI_2 / 2.0)));
+        assert!((half.rotate(from).angle_between(to) - FRAC_PI_2 / 2.0).abs() < EPS);
+    }
+
+    #[test]
+    fn homogeneous() {
+        let v = Vec3::new(1.0, -2.0, 3.0);
+
+        assert_eq!(v.to_homogeneous(1.0), [1.0, -2.0, 3.0, 1.0]);
+        assert_eq!(Vec3::from_homogeneous(v.to_homogeneous(1.0)), v);
+        assert_eq!(Vec3::from_homogeneous([2.0, -4.0, 6.0, 2.0]), v);
+        assert_eq!(Vec3::from_homogeneous(v.to_homogeneous(0.0)), v);
+    }
+
+    #[test]
+    fn outer_matrix() {
+        let m = Vec3::new(1.0, 2.0, 3.0).outer_matrix(Vec3::new(4.0, -5.0, 6.0));
+
+        assert_eq!(m[0][0], 4.0);
+        assert_eq!(m[0][1], -5.0);
+        assert_eq!(m[1][2], 12.0);
+        assert_eq!(m[2][1], -15.0);
+    }
+
+    #[test]
+    fn same_rotation() {
+        let r = Rotor3::from_to(Vec3::new(1.0, 0.0, 2.0), Vec3::new(0.0, -1.0, 1.0));
+        let other = Rotor3::from_to(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0));
+
+        assert!(r.represents_same_rotation(r));
+        assert!(r.represents_same_rotation(-r));
+        assert!(!r.is_close(-r));
+        assert!(!r.represents_same_rotation(other));
+    }
+
+    #[test]
+    fn bivector_from_axis() {
+        assert_eq!(BiVec3::from_axis(Vec3::unit(2)), BiVec3::new(1.0, 0.0, 0.0));
+        assert_eq!(BiVec3::from_axis(Vec3::unit(0)), BiVec3::new(0.0, 1.0, 0.0));
+        assert_eq!(BiVec3::from_axis(Vec3::unit(1)), BiVec3::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn slerp_with_tangent() {
+        let a = Vec3::new(2.0, 0.0, 0.0);
+        let b = Vec3::new(1.0, 1.0, 1.0);
+        let angle = (1.0 / 3.0_f64.sqrt()).acos();
+
+        let (start, _) = a.slerp_with_tangent(b, 0.0);
+        let (end, _) = a.slerp_with_tangent(b, 1.0);
+        assert!(start.is_close(a.normalized()));
+        assert!(end.is_close(b.normalized()));
+
+        for i in 0..=10 {
+            let (position, tangent) = a.slerp_with_tangent(b, i as f64 / 10.0);
+            assert!((position.length() - 1.0).abs() < EPS);
+            assert!(position.dot(tangent).abs() < EPS);
+            assert!((tangent.length() - angle).abs() < EPS);
+        }
+
+        let (mid, tangent) = a.slerp_with_tangent(-a, 0.5);
+        assert!((mid.length() - 1.0).abs() < EPS && mid.dot(a).abs() < EPS);
+        assert!(mid.dot(tangent).abs() < EPS && (tangent.length() - PI).abs() < EPS);
+        assert!(a.slerp_with_tangent(-a, 1.0).0.is_close(-a.normalized()));
+    }
+
+    #[test]
+    fn blend_linear() {
+        let a = Rotor3::from_to(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0));
+        let b = Rotor3::from_to(Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.0, 1.0, 1.0));
+
+        let raw = a.blend_linear(b, 0.5, false);
+        assert!(raw.is_close(Rotor3 {
+            e: (a.e + b.e) / 2.0,
+            e12: (a.e12 + b.e12) / 2.0,
+            e23: (a.e23 + b.e23) / 2.0,
+            e31: (a.e31 + b.e31) / 2.0,
+        }));
+        assert!(raw.magnitude_squared() < 1.0);
+        assert!(a.blend_linear(-b, 0.5, false).is_close(raw));
+
+        for i in 0..=10 {
+            let blend = a.blend_linear(-b, i as f64 / 10.0, true);
+            assert!((blend.magnitude_squared() - 1.0).abs() < EPS);
+        }
+        assert!(a.blend_linear(b, 0.0, true).is_close(a));
+        assert!(a.blend_linear(b, 1.0, true).is_close(b));
+    }
+
+    #[test]
+    fn close_direction() {
+        let x = Vec3::unit(0);
+
+        assert!(x.is_close_direction(Vec3::new(5.0, 0.0, 0.0)));
+        assert!(!x.is_close_direction(-x));
+        assert!(!x.is_close_direction(Vec3::unit(1)));
+        assert!(!x.is_close_direction(Vec3::ZERO));
+        assert!(!Vec3::ZERO.is_close_direction(Vec3::ZERO));
+    }
+
+    #[test]
+    fn clamp_to_sphere() {
+        let inside = Vec3::new(1.0, 2.0, 2.0);
+        let outside = Vec3::new(0.0, 6.0, 8.0);
+
+        assert_eq!(inside.clamp_to_ball(5.0), inside);
+        assert!(inside.clamp_to_sphere(6.0).is_close(2.0 * inside));
+        assert!(
+            outside
+                .clamp_to_ball(5.0)
+                .is_close(Vec3::new(0.0, 3.0, 4.0))
+        );
+        assert!(
+            outside
+                .clamp_to_sphere(5.0)
+                .is_close(Vec3::new(0.0, 3.0, 4.0))
+        );
+        assert_eq!(Vec3::ZERO.clamp_to_ball(5.0), Vec3::ZERO);
+        assert_eq!(Vec3::ZERO.clamp_to_sphere(5.0), Vec3::ZERO);
+    }
+
+    #[test]
+    fn multivector_blades() {
+        let m = MultiVec3::new(
+            1.0,
+            Vec3::new(2.0, 3.0, 4.0),
+            BiVec3::new(5.0, 6.0, 7.0),
+            TriVec3::new(8.0),
+        );
+
+        assert_eq!(m.to_blades(), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        assert_eq!(MultiVec3::from_blades(m.to_blades()), m);
+        assert_eq!(m.blade(4), m.e12);
+        assert_eq!(m.blade(7), m.e123);
+    }
+
+    #[test]
+    fn drop_axis() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+
+        assert_eq!(v.drop_x(), Vec3::new(0.0, 2.0, 3.0));
+        assert_eq!(v.drop_y(), Vec3::new(1.0, 0.0, 3.0));
+        assert_eq!(v.drop_z(), Vec3::new(1.0, 2.0, 0.0));
+        assert_eq!(v.onto_plane_yz(), v.drop_x());
+        assert_eq!(v.onto_plane_zx(), v.drop_y());
+        assert_eq!(v.onto_plane_xy(), v.drop_z());
+    }
+
+    #[test]
+    fn fresnel_split() {
+        let (z, down) = (Vec3::unit(2), -Vec3::unit(2));
+
+        let (reflected, refracted, reflectance) = down.fresnel_split(z, 1.0 / 1.5);
+        assert!(reflected.is_close(z));
+        assert!(refracted.unwrap().is_close(down));
+        assert!((reflectance - 0.04).abs() < EPS);
+
+        let grazing = Vec3::new(0.6_f64.sqrt(), 0.0, -0.4_f64.sqrt());
+        let (reflected, refracted, reflectance) = grazing.fresnel_split(z, 1.5);
+        assert!(reflected.is_close(Vec3::new(0.6_f64.sqrt(), 0.0, 0.4_f64.sqrt())));
+        assert_eq!((refracted, reflectance), (None, 1.0));
+
+        let refracted = Vec3::new(0.6, 0.0, -0.8).refract(z, 1.0 / 1.2).unwrap();
+        assert!((refracted.e1 - 0.5).abs() < EPS && (refracted.length() - 1.0).abs() < EPS);
+    }
+
+    #[test]
+    fn reflect_slice() {
+        let axis = Vec3::new(1.0, 2.0, -1.0);
+        let original = [
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(1.0, 1.0, 1.0),
+            Vec3::new(-3.0, 0.5, 4.0),
+        ];
+
+        let mut points = original;
+        Vec3::reflect_slice(&mut points, axis);
+        for (p, r) in original.iter().zip(points) {
+            assert_eq!(r, p.reflected_by(axis));
+        }
+
+        Vec3::reflect_slice(&mut [], axis);
+    }
+
+    #[test]
+    fn cylindrical() {
+        let points = [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(-2.0, 3.0, 1.0),
+            Vec3::new(0.5, -0.5, -4.0),
+            Vec3::new(-1.0, -1e-3, 2.0),
+        ];
+        for p in points {
+            let (rho, phi, z) = p.to_cylindrical();
+            assert!(Vec3::from_cylindrical(rho, phi, z).is_close(p));
+        }
+
+        assert_eq!(Vec3::new(0.0, 0.0, 7.0).to_cylindrical(), (0.0, 0.0, 7.0));
+        let (rho, phi, z) = Vec3::new(0.0, 2.0, -1.0).to_cylindrical();
+        assert!((rho - 2.0).abs() < EPS && (phi - FRAC_PI_2).abs() < EPS && z == -1.0);
+    }
+
+    #[test]
+    fn scalar_projection() {
+        let v = Vec3::new(3.0, 4.0, 0.0);
+
+        assert_eq!(v.scalar_projection(Vec3::new(1.0, 0.0, 0.0)), 3.0);
+        assert_eq!(v.scalar_projection(Vec3::new(-1.0, 0.0, 0.0)), -3.0);
+        assert_eq!(v.scalar_projection(Vec3::new(0.0, 2.0, 0.0)), 4.0);
+        assert_eq!(v.scalar_projection(Vec3::ZERO), 0.0);
+    }
+
+    #[test]
+    fn rotate_in_plane() {
+        let (x, y, z) = (Vec3::unit(0), Vec3::unit(1), Vec3::unit(2));
+
+        assert!(
+            Vec3::new(1.0, 0.0, 5.0)
+                .rotate_in_xy(FRAC_PI_2)
+                .is_close(Vec3::new(0.0, 1.0, 5.0))
+        );
+        assert!(y.rotate_in_yz(FRAC_PI_2).is_close(z));
+        assert!(z.rotate_in_zx(FRAC_PI_2).is_close(x));
+
+        let v = Vec3::new(1.0, -2.0, 3.0);
+        assert!(
+            v.rotate_in_xy(1.0)
+                .is_close(v.rotated_by(x, x.rotate_in_xy(1.0)))
+        );
+        assert!(
+            v.rotate_in_zx(-0.5)
+                .is_close(v.rotated_by(z, z.rotate_in_zx(-0.5)))
+        );
+    }
+
+    #[test]
+    fn angle_between_fast() {
+        let x = Vec3::unit(0);
+
+        for i in 1..12 {
+            let angle = PI * i as f64 / 12.0;
+            let v = x.rotate_in_xy(angle);
+            assert!((x.angle_between_fast(v) - angle).abs() < 1e-6);
+        }
+        assert_eq!(x.angle_between_fast(x * (1.0 + 1e-12)), 0.0);
+    }
+
+    #[test]
+    fn stable_basis() {
+        let reference = Vec3::new(0.0, 0.0, 1.0);
+        let mut previous = Vec3::new(1.0, 0.0, 0.0).stable_basis(reference);
+
+        for i in 1..=100 {
+            let direction = Vec3::new(1.0, 0.0, 0.2).rotate_in_xy(i as f64 * 0.05);
+            let (n, t, b) = direction.stable_basis(reference);
+
+            assert!(n.is_close(direction.normalized()));
+            assert!(n.dot(t).abs() < EPS && t.dot(b).abs() < EPS && b.dot(n).abs() < EPS);
+            assert!((t.length() - 1.0).abs() < EPS && (b.length() - 1.0).abs() < EPS);
+            assert!((n ^ t).dot(BiVec3::from_axis(b)) > 0.0);
+            assert!((t - previous.1).length() < 0.2 && (b - previous.2).length() < 0.2);
+            previous = (n, t, b);
+        }
+
+        let (n, t, b) = Vec3::new(0.0, 0.0, 2.0).stable_basis(reference);
+        assert!(n.dot(t).abs() < EPS && t.dot(b).abs() < EPS && b.dot(n).abs() < EPS);
+
+        let short = Vec3::new(0.0, 0.0, 1e-4);
+        assert!(Vec3::unit(0).stable_basis(short).1.is_close(Vec3::unit(2)));
+        let (_, t, _) = Vec3::unit(0).stable_basis(Vec3::ZERO);
+        assert!((t.length() - 1.0).abs() < EPS && t.dot(Vec3::unit(0)).abs() < EPS);
+    }
+
+    #[test]
+    fn multivector_sandwich() {
+        let vector = |v: Vec3| MultiVec3::from_scalar_and_vector(0.0, v);
+        let v = Vec3::new(1.0, -2.0, 0.5);
+
+        let axis = Vec3::new(1.0, 1.0, 0.0).normalized();
+        let reflected = Vec3::try_from(vector(v).sandwich(vector(axis))).unwrap();
+        assert!(reflected.is_close(v.reflected_by(axis)));
+
+        let (from, to) = (Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 1.0));
+        let r = Rotor3::from_to(from, to);
+        let rotated = Vec3::try_from(vector(v).sandwich(MultiVec3::from_rotor(r))).unwrap();
+        assert!(rotated.is_close(v.rotated_by(from, to)));
+    }
+
+    #[test]
+    fn project_to_rotor() {
+        let r = Rotor3::from_to(Vec3::new(1.0, 2.0, 0.0), Vec3::new(0.0, -1.0, 3.0));
+        let drifted = Rotor3 {
+            e: r.e * 1.01,
+            e12: r.e12 * 1.01,
+            ..r
+        };
+        let noisy = MultiVec3::new(
+            drifted.e,
+            Vec3::new(1e-4, -2e-4, 1e-4),
+            BiVec3::new(drifted.e12, drifted.e23, drifted.e31),
+            TriVec3::new(3e-4),
+        );
+
+        let cleaned = drifted.project_to_so3();
+        assert!((cleaned.magnitude_squared() - 1.0).abs() < EPS);
+        assert!(cleaned.dot(r) > 0.9999);
+
+        let projected = noisy.project_to_rotor();
+        assert!((projected.magnitude_squared() - 1.0).abs() < EPS);
+        assert!(projected.is_close(cleaned));
+    }
+
+    #[test]
+    fn manhattan_chebyshev() {
+        let v = Vec3::new(1.0, -2.0, 3.0);
+
+        assert_eq!(v.manhattan_length(), 6.0);
+        assert_eq!(v.chebyshev_length(), 3.0);
+        assert_eq!(v.manhattan_distance(Vec3::new(0.0, 2.0, 3.0)), 5.0);
+        assert_eq!(v.chebyshev_distance(Vec3::new(0.0, 2.0, 3.0)), 4.0);
+    }
+
+    #[test]
+    fn rotor_f32_array() {
+        let r = Rotor3::from_to(Vec3::new(1.0, 0.3, 0.0), Vec3::new(0.2, -1.0, 0.7));
+        let packed = r.to_f32_array();
+
+        assert_eq!(packed[0], r.e as f32);
+        assert_eq!(packed[3], r.e31 as f32);
+        let unpacked = Rotor3::from_f32_array(packed);
+        for (a, b) in [
+            (unpacked.e, r.e),
+            (unpacked.e12, r.e12),
+            (unpacked.e23, r.e23),
+            (unpacked.e31, r.e31),
+        ] {
+            assert!((a - b).abs() <= f32::EPSILON as f64);
+        }
+    }
+
+    #[test]
+    fn project_onto_unit() {
+        let v = Vec3::new(3.0, 4.0, -1.0);
+        let axis = Vec3::new(1.0, 1.0, 0.0).normalized();
+
+        assert!(v.project_onto_unit(axis).is_close(Vec3::new(3.5, 3.5, 0.0)));
+        assert!(
+            v.project_onto_unit(Vec3::unit(2))
+                .is_close(Vec3::new(0.0, 0.0, -1.0))
+        );
+        assert!((v.project_onto_unit(axis).length() - v.scalar_projection(axis)).abs() < EPS);
+    }
+
+    #[test]
+    fn reflect_across_line() {
+        let line = Vec3::new(1.0, 2.0, -2.0);
+        let v = Vec3::new(0.5, -1.0, 3.0);
+
+        assert_eq!(v.reflect_across_line(line), v.reflected_by(line));
+        assert!(line.reflect_across_line(line).is_close(line));
+        assert!(
+            Vec3::unit(1)
+                .reflect_across_line(Vec3::unit(0))
+                .is_close(-Vec3::unit(1))
+        );
+    }
+
+    #[test]
+    fn angle_wrapping() {
+        assert!((wrap_angle(3.0 * PI / 2.0) + FRAC_PI_2).abs() < EPS);
+        assert!((wrap_angle(-5.0 * PI / 2.0) + FRAC_PI_2).abs() < EPS);
+        assert!((wrap_angle(0.3 + 2.0 * TAU) - 0.3).abs() < EPS);
+        assert!((angle_difference(0.1, TAU - 0.1) - 0.2).abs() < EPS);
+        assert!((angle_difference(TAU - 0.1, 0.1) + 0.2).abs() < EPS);
+    }
+
+    #[test]
+    fn project_onto_plane_at() {
+        let point = Vec3::new(0.0, 0.0, 2.0);
+
+        assert_eq!(
+            Vec3::new(0.0, 0.0, 5.0).project_onto_plane_at(point, Vec3::unit(2)),
+            point
+        );
+        assert!(
+            Vec3::new(3.0, 1.0, 0.0)
+                .project_onto_plane_at(Vec3::new(1.0, 0.0, 0.0), Vec3::new(2.0, 2.0, 0.0))
+                .is_close(Vec3::new(1.5, -0.5, 0.0))
+        );
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v.project_onto_plane_at(point, Vec3::ZERO), v);
+    }
+
+    #[test]
+    fn rotor_reverse() {
+        let pairs = [
+            (Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0)),
+            (Vec3::new(1.0, 2.0, 3.0), Vec3::new(-2.0, 0.5, 1.0)),
+            (Vec3::new(0.0, 0.0, 1.0), Vec3::new(1.0, 1.0, 0.1)),
+        ];
+
+        for (from, to) in pairs {
+            let r = Rotor3::from_to(from, to);
+            assert_eq!(r.reverse().reverse(), r);
+            assert!((r * r.reverse()).is_close(Rotor3::IDENTITY));
+            assert!((r.reverse() * r).is_close(Rotor3::IDENTITY));
+
+            let scaled = Rotor3 {
+                e: 2.0 * r.e,
+                e12: 2.0 * r.e12,
+                e23: 2.0 * r.e23,
+                e31: 2.0 * r.e31,
+            };
+            let product = scaled * scaled.reverse();
+            assert!(product.is_close(Rotor3::new(scaled.magnitude_squared(), BiVec3::ZERO)));
+        }
+    }
+
+    #[test]
+    fn rotate() {
+        let (x, y, z) = (Vec3::unit(0), Vec3::unit(1), Vec3::unit(2));
+
+        let quarter = Rotor3::from_to(x, y);
+        assert!(quarter.rotate(x).is_close(y));
+        assert!(quarter.rotate(y).is_close(-x));
+        assert!(quarter.rotate(z).is_close(z));
+
+        // 120° about (1, 1, 1) cycles the basis vectors
+        let third = Rotor3::new(0.5, BiVec3::new(-0.5, -0.5, -0.5));
+        assert!(third.rotate(x).is_close(y));
+        assert!(third.rotate(y).is_close(z));
+        assert!(third.rotate(z).is_close(x));
+    }
+
+    #[test]
+    fn from_axis_angle() {
+        let (x, y, z) = (Vec3::unit(0), Vec3::unit(1), Vec3::unit(2));
+
+        for i in -8..=8 {
+            let angle = TAU * i as f64 / 8.0;
+            let (sin, cos) = angle.sin_cos();
+            let r = Rotor3::from_axis_angle(2.0 * z, angle);
+            assert!(r.rotate(x).is_close(Vec3::new(cos, sin, 0.0)));
+            assert!((r.magnitude_squared() - 1.0).abs() < EPS);
+        }
+
+        assert!(Rotor3::from_axis_angle(z, FRAC_PI_2).is_close(Rotor3::from_to(x, y)));
+        assert!(Rotor3::from_axis_angle(x, FRAC_PI_2).rotate(y).is_close(z));
+        assert_eq!(Rotor3::from_axis_angle(Vec3::ZERO, 1.0), Rotor3::IDENTITY);
+    }
+
+    #[test]
+    fn multivector_constructors() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        let b = BiVec3::new(4.0, 5.0, 6.0);
+
+        assert_eq!(
+            MultiVec3::from_scalar_and_vector(7.0, v).to_blades(),
+            [7.0, 1.0, 2.0, 3.0, 0.0, 0.0, 0.0, 0.0]
+        );
+        assert_eq!(
+            MultiVec3::from_scalar_and_bivector(7.0, b).to_blades(),
+            [7.0, 0.0, 0.0, 0.0, 4.0, 5.0, 6.0, 0.0]
+        );
+        assert_eq!(
+            MultiVec3::from_vector_and_bivector(v, b).to_blades(),
+            [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 0.0]
+        );
+        assert_eq!(
+            MultiVec3::from_rotor(Rotor3::new(7.0, b)).to_blades(),
+            [7.0, 0.0, 0.0, 0.0, 4.0, 5.0, 6.0, 0.0]
+        );
+        assert_eq!(
+            MultiVec3::from_dual_rotor(DualRotor3::new(v, TriVec3::new(8.0))).to_blades(),
+            [0.0, 1.0, 2.0, 3.0, 0.0, 0.0, 0.0, 8.0]
+        );
+    }
+
+    #[test]
+    fn to_axis_angle() {
+        let axes = [
+            Vec3::unit(2),
+            Vec3::new(1.0, -2.0, 0.5).normalized(),
+            Vec3::new(-0.3, 0.1, -0.9).normalized(),
+        ];
+
+        for axis in axes {
+            for angle in [0.1, 1.0, FRAC_PI_2, 3.0, 5.0] {
+                let (a, theta) = Rotor3::from_axis_angle(axis, angle).to_axis_angle();
+                assert!(a.is_close(axis));
+                assert!((theta - angle).abs() < EPS);
+            }
+        }
+
+        let (axis, angle) = Rotor3::IDENTITY.to_axis_angle();
+        assert_eq!((axis, angle), (Vec3::unit(0), 0.0));
+    }
+
+    #[test]
+    fn reflect_across_bisector() {
+        let a = Vec3::new(3.0, 0.0, 4.0);
+        let b = Vec3::new(0.0, 5.0, 0.0);
+
+        assert!(a.reflect_across_bisector(a, b).is_close(b));
+        assert!(b.reflect_across_bisector(a, b).is_close(a));
+        let on_plane = (a + b) / 2.0;
+        assert!(on_plane.reflect_across_bisector(a, b).is_close(on_plane));
+        assert_eq!(a.reflect_across_bisector(b, 2.0 * b), a);
+    }
+
+    #[test]
+    fn slerp() {
+        let axis = Vec3::new(1.0, 2.0, 2.0);
+        let a = Rotor3::from_axis_angle(axis, 0.4);
+        let b = Rotor3::from_axis_angle(axis, 2.0);
+
+        assert!(a.slerp(b, 0.0).is_close(a));
+        assert!(a.slerp(b, 1.0).is_close(b));
+        assert!(a.slerp(b, 0.5).is_close(Rotor3::from_axis_angle(axis, 1.2)));
+        assert!(
+            a.slerp(b, 0.25)
+                .is_close(Rotor3::from_axis_angle(axis, 0.8))
+        );
+        assert!(a.slerp(-b, 0.5).is_close(a.slerp(b, 0.5)));
+
+        let c = Rotor3::from_axis_angle(Vec3::new(-1.0, 0.0, 3.0), 2.5);
+        for i in 0..=10 {
+            let r = a.slerp(c, i as f64 / 10.0);
+            assert!((r.magnitude_squared() - 1.0).abs() < EPS);
+        }
+        let close = Rotor3::from_axis_angle(axis, 0.4 + 1e-9);
+        assert!(a.slerp(close, 0.5).is_close(a));
+    }
+
+    #[test]
+    fn smooth_damp() {
+        let target = Rotor3::from_axis_angle(Vec3::new(1.0, -1.0, 0.5), 2.0);
+        let angle = |r: Rotor3| 2.0 * r.dot(target).abs().min(1.0).acos();
+        let mut r = Rotor3::from_axis_angle(Vec3::unit(2), -0.7);
+        let mut velocity = BiVec3::ZERO;
+
+        let mut previous = angle(r);
+        for _ in 0..600 {
+            r = r.smooth_damp(target, &mut velocity, 0.3, 1.0 / 60.0);
+            assert!(angle(r) <= previous + 1e-12);
+            previous = angle(r);
+        }
+        assert!(r.is_close_rotation(target, 1e-6));
+    }
+
+    #[test]
+    fn slerp_derivative() {
+        let a = Rotor3::from_axis_angle(Vec3::new(1.0, 0.5, 0.0), 0.4);
+        let b = Rotor3::from_axis_angle(Vec3::new(-1.0, 1.0, 2.0), 2.1);
+        let h = 1e-5;
+
+        for other in [b, -b] {
+            for t in [0.0, 0.2, 0.5, 0.9, 1.0] {
+                let (before, after) = (a.slerp(other, t - h), a.slerp(other, t + h));
+                let d = a.slerp_derivative(other, t);
+                let finite = [
+                    after.e - before.e,
+                    after.e12 - before.e12,
+                    after.e23 - before.e23,
+                    after.e31 - before.e31,
+                ]
+                .map(|x| x / (2.0 * h));
+                for (x, y) in finite.iter().zip([d.e, d.e12, d.e23, d.e31]) {
+                    assert!((x - y).abs() < 1e-6);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rotor_try_slerp() {
+        let a = Rotor3::from_axis_angle(Vec3::unit(0), 0.4);
+        let b = Rotor3::from_axis_angle(Vec3::new(1.0, -2.0, 0.5), 1.5);
+
+        assert!(a.try_slerp(b, 0.3).unwrap().is_close(a.slerp(b, 0.3)));
+        let nan = Rotor3::new(f64::NAN, BiVec3::ZERO);
+        assert_eq!(a.try_slerp(nan, 0.5), Err(SlerpError::NonFinite));
+        assert_eq!(a.try_slerp(b, f64::INFINITY), Err(SlerpError::NonFinite));
+        assert_eq!(
+            Rotor3::new(2.0, BiVec3::ZERO).try_slerp(b, 0.5),
+            Err(SlerpError::NotNormalized)
+        );
+        assert_eq!(
+            a.try_slerp(Rotor3::ZERO, 0.5),
+            Err(SlerpError::NotNormalized)
+        );
+    }
+
+    #[test]
+    fn slerp_with_flip_info() {
+        let a = Rotor3::from_axis_angle(Vec3::unit(0), 0.3);
+        let b = Rotor3::from_axis_angle(Vec3::new(0.0, 1.0, -1.0), 1.2);
+
+        let (r, flipped) = a.slerp_with_flip_info(b, 0.4);
+        assert!(r.is_close(a.slerp(b, 0.4)) && !flipped);
+        let (r, flipped) = a.slerp_with_flip_info(-b, 0.4);
+        assert!(r.is_close(a.slerp(b, 0.4)) && flipped);
+        // Rotations 1.9π apart, the shortest path goes the other way round
+        let far = Rotor3::from_axis_angle(Vec3::unit(0), 0.3 + 1.9 * PI);
+        assert!(a.slerp_with_flip_info(far, 0.5).1);
+    }
+
+    #[test]
+    fn slerp_eased() {
+        let a = Rotor3::from_axis_angle(Vec3::unit(1), -0.5);
+        let b = Rotor3::from_axis_angle(Vec3::new(1.0, 1.0, 0.0), 2.0);
+        let smoothstep = |t: f64| t * t * (3.0 - 2.0 * t);
+
+        for t in [0.0, 0.3, 0.5, 1.0] {
+            assert!(a.slerp_eased(b, t, |t| t).is_close(a.slerp(b, t)));
+        }
+        assert!(a.slerp_eased(b, 0.1, smoothstep).dot(a) > a.slerp(b, 0.1).dot(a));
+        assert!(a.slerp_eased(b, 0.9, smoothstep).dot(b) > a.slerp(b, 0.9).dot(b));
+        assert!(a.slerp_eased(b, 1.0, smoothstep).is_close(b));
+    }
+
+    #[test]
+    fn piecewise_slerp() {
+        let keys = [
+            Rotor3::IDENTITY,
+            Rotor3::from_axis_angle(Vec3::unit(2), 1.0),
+            Rotor3::from_axis_angle(Vec3::new(1.0, 0.0, 1.0), 2.0),
+        ];
+
+        for (i, key) in keys.iter().enumerate() {
+            assert!(Rotor3::piecewise_slerp(&keys, i as f64).is_close(*key));
+        }
+        assert!(Rotor3::piecewise_slerp(&keys, 0.5).is_close(keys[0].slerp(keys[1], 0.5)));
+        assert!(Rotor3::piecewise_slerp(&keys, 1.25).is_close(keys[1].slerp(keys[2], 0.25)));
+        assert!(Rotor3::piecewise_slerp(&keys, -1.0).is_close(keys[0]));
+        assert!(Rotor3::piecewise_slerp(&keys, 7.0).is_close(keys[2]));
+        assert_eq!(Rotor3::piecewise_slerp(&keys[1..2], 0.5), keys[1]);
+        assert_eq!(Rotor3::piecewise_slerp(&[], 0.5), Rotor3::IDENTITY);
+    }
+
+    #[test]
+    fn nlerp() {
+        let a = Rotor3::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), 0.3);
+        let b = Rotor3::from_axis_angle(Vec3::new(1.0, 1.0, -2.0), 2.8);
+
+        assert!(a.nlerp(b, 0.0).is_close(a));
+        assert!(a.nlerp(b, 1.0).is_close(b));
+        assert!(a.nlerp(-b, 1.0).is_close(b));
+        assert!(a.nlerp(b, 0.5).is_close(a.slerp(b, 0.5)));
+        for i in 0..=10 {
+            let r = a.nlerp(-b, i as f64 / 10.0);
+            assert!((r.magnitude_squared() - 1.0).abs() < EPS);
+        }
+    }
+
+    #[test]
+    fn exp_log_map() {
+        let tangents = [
+            Vec3::new(0.3, -0.2, 0.1),
+            Vec3::new(0.0, 0.0, 3.0),
+            Vec3::new(-1.0, 2.0, 0.5),
+            Vec3::new(1e-9, 0.0, -2e-9),
+            Vec3::ZERO,
+        ];
+
+        for tangent in tangents {
+            let r = Rotor3::exp_map(tangent);
+            assert!((r.magnitude_squared() - 1.0).abs() < EPS);
+            assert!(r.log_map().is_close(tangent));
+            assert!(Rotor3::exp_map(r.log_map()).is_close(r));
+        }
+
+        let r = Rotor3::from_axis_angle(Vec3::new(1.0, 1.0, 0.0), 1.5);
+        assert!(Rotor3::exp_map(r.log_map()).is_close(r));
+        assert!(
+            Rotor3::exp_map(Vec3::unit(2) * FRAC_PI_2)
+                .rotate(Vec3::unit(0))
+                .is_close(Vec3::unit(1))
+        );
+    }
+
+    #[test]
+    fn rotation_vector() {
+        for rv in [
+            Vec3::new(0.4, 0.0, -1.1),
+            Vec3::new(-2.0, 1.0, 0.5),
+            Vec3::new(3e-10, -1e-10, 2e-10),
+        ] {
+            let r = Rotor3::from_rotation_vector(rv);
+            assert!(r.is_close(Rotor3::from_axis_angle(rv, rv.length())));
+            assert!(r.to_rotation_vector().is_close(rv));
+        }
+        assert_eq!(Rotor3::from_rotation_vector(Vec3::ZERO), Rotor3::IDENTITY);
+    }
+
+    #[test]
+    fn angular_velocity() {
+        let dt = 1.0 / 60.0;
+        for omega in [
+            Vec3::new(0.5, -1.0, 2.0),
+            Vec3::new(0.0, 1e-6, 0.0),
+            Vec3::new(-3.0, 0.2, 0.1),
+        ] {
+            let r = Rotor3::from_angular_velocity(omega, dt);
+            assert!(r.to_angular_velocity(dt).is_close(omega));
+        }
+
+        let r = Rotor3::from_angular_velocity(Vec3::unit(2), 0.1);
+        assert!(r.is_close(Rotor3::from_axis_angle(Vec3::unit(2), 0.1)));
+        let b = r.ln();
+        assert!((-2.0 * Vec3::new(b.e23, b.e31, b.e12) / 0.1).is_close(Vec3::unit(2)));
+    }
+
+    #[test]
+    fn rotor_normalize() {
+        let step = Rotor3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.1);
+        let drifted_step = Rotor3 {
+            e: step.e * (1.0 + 1e-6),
+            ..step
+        };
+        let mut r = Rotor3::IDENTITY;
+        for _ in 0..1000 {
+            r = r * drifted_step;
+        }
+
+        assert!((r.magnitude() - 1.0).abs() > 1e-4);
+        assert!((r.normalize().magnitude() - 1.0).abs() < EPS);
+        assert!(r.normalize_or_identity().is_close(r.normalize()));
+        assert_eq!(
+            Rotor3::new(3.0, BiVec3::new(0.0, 4.0, 0.0)).magnitude_squared(),
+            25.0
+        );
+        assert_eq!(Rotor3::ZERO.normalize_or_identity(), Rotor3::IDENTITY);
+    }
+
+    #[test]
+    fn to_matrix() {
+        let is_close = |a: [[f64; 3]; 3], b: [[f64; 3]; 3]| {
+            (0..3).all(|i| (0..3).all(|j| (a[i][j] - b[i][j]).abs() < EPS))
+        };
+        let (sin, cos) = 0.7_f64.sin_cos();
+
+        assert!(is_close(
+            Rotor3::from_axis_angle(Vec3::unit(0), 0.7).to_matrix(),
+            [[1.0, 0.0, 0.0], [0.0, cos, -sin], [0.0, sin, cos]]
+        ));
+        assert!(is_close(
+            Rotor3::from_axis_angle(Vec3::unit(1), 0.7).to_matrix(),
+            [[cos, 0.0, sin], [0.0, 1.0, 0.0], [-sin, 0.0, cos]]
+        ));
+        assert!(is_close(
+            Rotor3::from_axis_angle(Vec3::unit(2), 0.7).to_matrix(),
+            [[cos, -sin, 0.0], [sin, cos, 0.0], [0.0, 0.0, 1.0]]
+        ));
+        assert!(is_close(
+            Rotor3::IDENTITY.to_matrix(),
+            [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+        ));
+    }
+
+    #[test]
+    fn to_matrix_array() {
+        let r = Rotor3::from_axis_angle(Vec3::new(1.0, -2.0, 0.5), 0.9);
+        let m = r.to_matrix();
+        let (rows, columns) = (r.to_matrix_array(false), r.to_matrix_array(true));
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(rows[3 * i + j], columns[3 * j + i]);
+                assert_eq!(columns[3 * j + i], m[i][j]);
+            }
+        }
+        assert_eq!(rows[1], m[0][1]);
+    }
+
+    #[test]
+    fn from_matrix() {
+        let rotors = [
+            Rotor3::IDENTITY,
+            Rotor3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.5),
+            Rotor3::from_axis_angle(Vec3::new(-1.0, 0.5, 0.0), 2.5),
+            Rotor3::from_axis_angle(Vec3::unit(0), PI),
+            Rotor3::from_axis_angle(Vec3::unit(1), PI),
+            Rotor3::from_axis_angle(Vec3::new(0.0, 1.0, 1.0), PI),
+            Rotor3::from_axis_angle(Vec3::new(0.3, -0.4, 2.0), 5.0),
+        ];
+
+        for r in rotors {
+            assert!(Rotor3::from_matrix(r.to_matrix()).is_close_rotation(r, EPS));
+        }
+    }
+
+    #[test]
+    fn from_matrix_orthonormalized() {
+        let r = Rotor3::from_axis_angle(Vec3::new(1.0, 2.0, -1.0), 0.9);
+        let mut m = r.to_matrix();
+        m[0][1] += 0.01;
+        m[2][0] -= 0.02;
+        for row in &mut m {
+            row[2] *= 1.05;
+        }
+
+        let fixed = Rotor3::from_matrix_orthonormalized(m);
+        assert!((fixed.magnitude() - 1.0).abs() < EPS);
+        assert!(fixed.is_close_rotation(r, 0.02));
+        assert!(Rotor3::from_matrix_orthonormalized(r.to_matrix()).is_close_rotation(r, EPS));
+
+        let mut reflected = r.to_matrix();
+        for row in &mut reflected {
+            row[2] = -row[2];
+        }
+        assert!(Rotor3::from_matrix_orthonormalized(reflected).is_close_rotation(r, EPS));
+    }
+
+    #[test]
+    fn unrotate_slice() {
+        let r = Rotor3::from_axis_angle(Vec3::new(2.0, -1.0, 0.5), 1.3);
+        let original = [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(-2.0, 3.0, 1.0),
+            Vec3::new(0.5, 0.5, -4.0),
+        ];
+
+        let mut points = r.rotate_vec(&original);
+        r.unrotate_slice(&mut points);
+        for (p, o) in points.iter().zip(original) {
+            assert!(p.is_close(o));
+        }
+
+        let mut points = original;
+        r.unrotate_slice(&mut points);
+        assert!(points[1].is_close(r.reverse().rotate(original[1])));
+        r.unrotate_slice(&mut []);
+    }
+
+    #[test]
+    fn from_euler() {
+        // Rz(yaw) * Ry(pitch) * Rx(roll) applied to v
+        let reference = |yaw: f64, pitch: f64, roll: f64, v: Vec3| {
+            let ((sy, cy), (sp, cp), (sr, cr)) = (yaw.sin_cos(), pitch.sin_cos(), roll.sin_cos());
+            let v = Vec3::new(v.e1, cr * v.e2 - sr * v.e3, sr * v.e2 + cr * v.e3);
+            let v = Vec3::new(cp * v.e1 + sp * v.e3, v.e2, cp * v.e3 - sp * v.e1);
+            Vec3::new(cy * v.e1 - sy * v.e2, sy * v.e1 + cy * v.e2, v.e3)
+        };
+        let v = Vec3::new(1.0, -2.0, 0.5);
+        let angles = [
+            (0.0, 0.0, 0.0),
+            (0.3, 0.0, 0.0),
+            (0.0, 0.3, 0.0),
+            (0.0, 0.0, 0.3),
+            (1.0, -0.5, 2.0),
+            (-2.5, 1.2, -0.7),
+            (0.4, FRAC_PI_2, 0.9),
+            (0.4, -FRAC_PI_2 + 1e-6, -1.1),
+        ];
+
+        for (yaw, pitch, roll) in angles {
+            let r = Rotor3::from_euler(yaw, pitch, roll);
+            assert!(r.rotate(v).is_close(reference(yaw, pitch, roll, v)));
+        }
+    }
+
+    #[test]
+    fn octahedral() {
+        let (x, y, z) = (Vec3::unit(0), Vec3::unit(1), Vec3::unit(2));
+        let normals = [
+            x,
+            -y,
+            z,
+            -z,
+            Vec3::new(1.0, 1.0, 1.0).normalized(),
+            Vec3::new(-0.3, 0.8, -0.5).normalized(),
+            Vec3::new(0.6, -0.2, -0.9).normalized(),
+        ];
+
+        for n in normals {
+            let (u, v) = n.to_oct();
+            assert!((-1.0..=1.0).contains(&u) && (-1.0..=1.0).contains(&v));
+            assert!(Vec3::from_oct((u, v)).is_close(n));
+        }
+
+        assert!(Vec3::from_oct((0.0, 0.0)).is_close(z));
+        assert!(Vec3::from_oct((1.0, 1.0)).is_close(-z));
+        assert!(Vec3::from_oct((1.0, 0.0)).is_close(x));
+        assert!(Vec3::from_oct((0.5, -0.5)).is_close(Vec3::new(1.0, -1.0, 0.0).normalized()));
+    }
+
+    #[test]
+    fn to_euler() {
+        let angles = [
+            (0.0, 0.0, 0.0),
+            (0.3, -0.2, 0.1),
+            (1.0, -0.5, 2.0),
+            (-2.5, 1.2, -0.7),
+            (3.0, 1.5, -3.0),
+        ];
+        for (yaw, pitch, roll) in angles {
+            let (y, p, r) = Rotor3::from_euler(yaw, pitch, roll).to_euler();
+            assert!(Vec3::new(y, p, r).is_close(Vec3::new(yaw, pitch, roll)));
+        }
+
+        for pitch in [FRAC_PI_2, -FRAC_PI_2] {
+            let rotor = Rotor3::from_euler(0.4, pitch, 0.9);
+            let (y, p, r) = rotor.to_euler();
+            assert!(y.is_finite() && (p - pitch).abs() < 1e-3 && r == 0.0);
+            assert!(Rotor3::from_euler(y, p, r).is_close_rotation(rotor, EPS));
+        }
+
+        // Close to, but not at, the singular pitch
+        for pitch in [FRAC_PI_2 - 4e-4, -FRAC_PI_2 + 1e-6] {
+            let rotor = Rotor3::from_euler(0.4, pitch, 0.9);
+            let (y, p, r) = rotor.to_euler();
+            assert!(Vec3::new(y, p, r).is_close(Vec3::new(0.4, pitch, 0.9)));
+            let v = Vec3::new(1.0, -2.0, 0.5);
+            assert!(
+                Rotor3::from_euler(y, p, r)
+                    .rotate(v)
+                    .is_close(rotor.rotate(v))
+            );
+        }
+    }
+
+    #[test]
+    fn weighted_sum() {
+        let (x, y) = (Vec3::unit(0), Vec3::unit(1));
+
+        assert_eq!(
+            Vec3::weighted_sum(&[x, y], &[2.0, 3.0]),
+            Vec3::new(2.0, 3.0, 0.0)
+        );
+        assert_eq!(
+            Vec3::weighted_sum(&[x, Vec3::new(1.0, 2.0, 3.0)], &[-1.0, 0.5]),
+            Vec3::new(-0.5, 1.0, 1.5)
+        );
+        assert_eq!(Vec3::weighted_sum(&[], &[]), Vec3::ZERO);
+    }
+
+    #[test]
+    fn quaternion() {
+        // q * v * q⁻¹ for a unit quaternion
+        let rotate = |(w, x, y, z): (f64, f64, f64, f64), v: Vec3| {
+            let u = Vec3::new(x, y, z);
+            let t = 2.0 * u.cross(v);
+            v + w * t + u.cross(t)
+        };
+        let v = Vec3::new(1.0, -2.0, 0.5);
+        let rotors = [
+            Rotor3::IDENTITY,
+            Rotor3::from_axis_angle(Vec3::unit(2), FRAC_PI_2),
+            Rotor3::from_axis_angle(Vec3::new(1.0, 2.0, -1.0), 2.2),
+        ];
+
+        for r in rotors {
+            let (w, x, y, z) = r.to_quaternion();
+            assert_eq!(Rotor3::from_quaternion(w, x, y, z), r);
+            assert!(rotate((w, x, y, z), v).is_close(r.rotate(v)));
+        }
+
+        let (sin, cos) = (PI / 6.0).sin_cos();
+        let (w, x, y, z) = Rotor3::from_axis_angle(Vec3::unit(0), PI / 3.0).to_quaternion();
+        assert!((w - cos).abs() < EPS);
+        assert!(Vec3::new(x, y, z).is_close(Vec3::new(sin, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn bivector_exp() {
+        let axes = [
+            Vec3::unit(2),
+            Vec3::new(1.0, -2.0, 2.0).normalized(),
+            Vec3::new(0.2, 0.1, -0.7).normalized(),
+        ];
+
+        for axis in axes {
+            for angle in [0.0, 1e-9, 0.5, 2.0, 5.0] {
+                let plane = BiVec3::from_axis(axis);
+                let r = Rotor3::from_axis_angle(axis, angle);
+                assert!((-angle / 2.0 * plane).exp().is_close(r));
+            }
+        }
+
+        assert_eq!(BiVec3::ZERO.exp(), Rotor3::IDENTITY);
+    }
+
+    #[test]
+    fn bivector_exp_is_unit() {
+        let direction = Vec3::new(0.3, -1.0, 0.6).normalized();
+        // Magnitudes from 1e-8 to 10, 10 per decade
+        for i in 0..=90 {
+            let magnitude = 10.0_f64.powf(-8.0 + i as f64 / 10.0);
+            for axis in [Vec3::unit(1), direction] {
+                let r = BiVec3::from_axis(magnitude * axis).exp();
+                assert!((r.magnitude() - 1.0).abs() < EPS);
+            }
+        }
+    }
+
+    #[test]
+    fn rotor_between() {
+        let (e12, e23) = (BiVec3::new(1.0, 0.0, 0.0), BiVec3::new(0.0, 1.0, 0.0));
+        // The bivector part of b sandwiched by r
+        let rotate = |b: BiVec3, r: Rotor3| {
+            let m = MultiVec3::from_scalar_and_bivector(0.0, b).sandwich(MultiVec3::from_rotor(r));
+            let [.., e12, e23, e31, _] = m.to_blades();
+            Vec3::new(e12, e23, e31)
+        };
+
+        assert!(rotate(e12, e12.rotor_between(e23)).is_close(Vec3::new(0.0, 1.0, 0.0)));
+        let b = BiVec3::new(0.3, -1.0, 0.5);
+        let rotated = rotate(2.0 * e23, e23.rotor_between(b));
+        assert!(rotated.is_close(2.0 * Vec3::new(0.3, -1.0, 0.5).normalized()));
+    }
+
+    #[test]
+    fn versor() {
+        let vector = MultiVec3::from_scalar_and_vector(0.0, Vec3::new(1.0, 2.0, 2.0));
+        let rotor = MultiVec3::from_rotor(Rotor3::from_axis_angle(Vec3::new(1.0, 0.0, 1.0), 0.8));
+        let mixed = MultiVec3::from_scalar_and_vector(1.0, Vec3::new(1.0, 0.0, 0.0));
+
+        assert!(vector.is_versor(EPS));
+        assert!(rotor.is_versor(EPS));
+        assert!((vector * rotor).is_versor(EPS));
+        assert!(!mixed.is_versor(EPS));
+        let scalar_pseudoscalar = MultiVec3::new(1.0, Vec3::ZERO, BiVec3::ZERO, TriVec3::new(1.0));
+        assert!(!scalar_pseudoscalar.is_versor(EPS));
+
+        let unit = vector.normalized_versor();
+        assert!(
+            Vec3::try_from(unit)
+                .unwrap()
+                .is_close(Vec3::new(1.0, 2.0, 2.0) / 3.0)
+        );
+        assert!(((unit * unit.reverse()).e - 1.0).abs() < EPS);
+    }
+
+    #[test]
+    fn rotor_ln() {
+        let rotors = [
+            Rotor3::IDENTITY,
+            Rotor3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 1e-9),
+            Rotor3::from_axis_angle(Vec3::unit(0), 0.5),
+            Rotor3::from_axis_angle(Vec3::new(-1.0, 0.5, 2.0), 2.5),
+            Rotor3::from_axis_angle(Vec3::new(0.0, 1.0, -1.0), 5.0),
+        ];
+
+        for r in rotors {
+            assert!(r.ln().exp().is_close(r));
+        }
+
+        let plane = BiVec3::new(0.3, -0.2, 0.4);
+        let b = plane.exp().ln();
+        assert!(Vec3::new(b.e12, b.e23, b.e31).is_close(Vec3::new(0.3, -0.2, 0.4)));
+        assert_eq!(Rotor3::IDENTITY.ln(), BiVec3::ZERO);
+
+        // Half-angles near π, where the sign of the scalar part matters
+        let minus_identity = -Rotor3::IDENTITY;
+        assert!((minus_identity.ln().dot(minus_identity.ln()).sqrt() - PI).abs() < EPS);
+        assert!(minus_identity.ln().exp().is_close(minus_identity));
+        let full_turn = Rotor3::from_axis_angle(Vec3::unit(2), TAU);
+        assert!(full_turn.ln().exp().is_close(full_turn));
+        let almost_full_turn = Rotor3::from_axis_angle(Vec3::unit(2), TAU - 1e-6);
+        let (b, c) = (full_turn.ln(), almost_full_turn.ln());
+        assert!((b.dot(b).sqrt() - PI).abs() < EPS && (c.dot(c).sqrt() - PI).abs() < 1e-6);
+        let plane = BiVec3::new(PI, 0.0, 0.0);
+        assert!(plane.exp().ln().exp().is_close(plane.exp()));
+    }
+
+    #[test]
+    fn powf() {
+        let r = Rotor3::from_axis_angle(Vec3::new(1.0, -1.0, 2.0), 1.1);
+        let half = r.powf(0.5);
+
+        assert!((half * half).is_close(r));
+        assert!(r.powf(2.0).is_close(r * r));
+        assert!(r.powf(1.0).is_close(r));
+        assert!(r.powf(0.0).is_close(Rotor3::IDENTITY));
+        assert!(r.powf(-1.0).is_close(r.reverse()));
+        assert!(half.is_close(Rotor3::from_axis_angle(Vec3::new(1.0, -1.0, 2.0), 0.55)));
+
+        let full_turn = Rotor3::from_axis_angle(Vec3::unit(2), TAU);
+        assert!(
+            full_turn
+                .powf(0.5)
+                .is_close(Rotor3::from_axis_angle(Vec3::unit(2), PI))
+        );
+    }
+
+    #[test]
+    fn apply_additive() {
+        let base = Rotor3::from_axis_angle(Vec3::new(0.0, 1.0, 1.0), 0.8);
+        let delta = Rotor3::from_axis_angle(Vec3::unit(0), 0.6);
+
+        assert!(base.apply_additive(delta, 0.0).is_close(base));
+        assert!(base.apply_additive(delta, 1.0).is_close(delta * base));
+        assert!(
+            base.apply_additive(delta, 0.5)
+                .is_close(Rotor3::from_axis_angle(Vec3::unit(0), 0.3) * base)
+        );
+    }
+
+    #[test]
+    fn try_slerp() {
+        let (x, y) = (Vec3::unit(0), Vec3::unit(1));
+
+        let mid = x.try_slerp(3.0 * y, 0.5).unwrap();
+        assert!(mid.is_close(Vec3::new(1.0, 1.0, 0.0).normalized()));
+        assert!(x.try_slerp(x, 0.3).unwrap().is_close(x));
+        assert_eq!(x.try_slerp(Vec3::ZERO, 0.5), Err(SlerpError::ZeroLength));
+        assert_eq!(
+            Vec3::new(1e-9, 0.0, 0.0).try_slerp(y, 0.5),
+            Err(SlerpError::ZeroLength)
+        );
+        assert_eq!(x.try_slerp(-2.0 * x, 0.5), Err(SlerpError::Antiparallel));
+    }
+
+    #[test]
+    fn barycentric_blend() {
+        let a = Rotor3::from_axis_angle(Vec3::unit(0), 0.4);
+        let b = Rotor3::from_axis_angle(Vec3::unit(1), 1.2);
+        let c = -Rotor3::from_axis_angle(Vec3::new(1.0, 1.0, 1.0), 0.9);
+
+        assert!(Rotor3::barycentric_blend(a, b, c, 1.0, 0.0).is_close(a));
+        assert!(Rotor3::barycentric_blend(a, b, c, 0.0, 1.0).is_close(b));
+        assert!(Rotor3::barycentric_blend(a, b, c, 0.0, 0.0).is_close_rotation(c, EPS));
+
+        let third = 1.0 / 3.0;
+        let mean = Rotor3 {
+            e: a.e + b.e - c.e,
+            e12: a.e12 + b.e12 - c.e12,
+            e23: a.e23 + b.e23 - c.e23,
+            e31: a.e31 + b.e31 - c.e31,
+        }
+        .normalize();
+        assert!(Rotor3::barycentric_blend(a, b, c, third, third).is_close(mean));
+        assert!(Rotor3::barycentric_blend(a, b, c, 0.5, 0.5).is_close(a.nlerp(b, 0.5)));
+    }
+
+    #[test]
+    fn cross() {
+        let (x, y, z) = (Vec3::unit(0), Vec3::unit(1), Vec3::unit(2));
+
+        assert_eq!(x.cross(y), z);
+        assert_eq!(y.cross(z), x);
+        assert_eq!(z.cross(x), y);
+        assert_eq!(y.cross(x), -z);
+        assert_eq!(x.cross(x), Vec3::ZERO);
+        assert_eq!(
+            Vec3::new(1.0, 2.0, 3.0).cross(Vec3::new(4.0, 5.0, 6.0)),
+            Vec3::new(-3.0, 6.0, -3.0)
+        );
+        assert_eq!(BiVec3::from_axis(x.cross(y)), x ^ y);
+    }
+
+    #[test]
+    fn mirror() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+
+        assert_eq!(v.mirror_xy(), Vec3::new(1.0, 2.0, -3.0));
+        assert_eq!(v.mirror_yz(), Vec3::new(-1.0, 2.0, 3.0));
+        assert_eq!(v.mirror_zx(), Vec3::new(1.0, -2.0, 3.0));
+        assert!(
+            v.mirror_xy()
+                .is_close(v.reflect_across_bisector(Vec3::unit(2), -Vec3::unit(2)))
+        );
+    }
+
+    #[test]
+    fn project_reject() {
+        let v = Vec3::new(1.0, 1.0, 0.0);
+        let x = Vec3::new(3.0, 0.0, 0.0);
+
+        assert_eq!(v.project_onto(x), Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(v.reject_from(x), Vec3::new(0.0, 1.0, 0.0));
+
+        let v = Vec3::new(2.0, -1.0, 4.0);
+        let onto = Vec3::new(1.0, 2.0, 2.0);
+        assert!((v.project_onto(onto) + v.reject_from(onto)).is_close(v));
+        assert!(v.reject_from(onto).dot(onto).abs() < EPS);
+        assert!(
+            v.project_onto(onto)
+                .is_close(v.project_onto_unit(onto.normalized()))
+        );
+    }
+
+    #[test]
+    fn angle_between() {
+        let x = Vec3::new(2.0, 0.0, 0.0);
+
+        assert!((x.angle_between(Vec3::new(0.0, 0.0, 3.0)) - FRAC_PI_2).abs() < EPS);
+        assert_eq!(x.angle_between(Vec3::new(5.0, 0.0, 0.0)), 0.0);
+        assert!((x.angle_between(Vec3::new(-1.0, 0.0, 0.0)) - PI).abs() < EPS);
+        assert!((x.angle_between(Vec3::new(1.0, 1.0, 0.0)) - PI / 4.0).abs() < EPS);
+        assert!((x.angle_between(x.rotate_in_xy(1e-9)) - 1e-9).abs() < 1e-15);
+
+        let (a, b) = (
+            Vec3::new(1.0, 2.0, 2.0).normalized(),
+            Vec3::new(-1.0, 0.0, 1.0).normalized(),
+        );
+        assert!((a.angle_between(b) - a.angle_between_fast(b)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn close_rotation() {
+        let r = Rotor3::from_axis_angle(Vec3::new(1.0, -2.0, 0.5), 2.0);
+        let near_half_turn = Rotor3::from_axis_angle(Vec3::unit(2), PI - 1e-9);
+        let past_half_turn = Rotor3::from_axis_angle(Vec3::unit(2), PI + 1e-9);
+
+        assert!(r.is_close_rotation(-r, EPS));
+        assert!(!r.is_close(-r));
+        assert!(near_half_turn.is_close_rotation(past_half_turn, EPS));
+        assert!(!r.is_close_rotation(r.powf(1.01), 1e-3 / 2.0));
+        assert!(r.is_close_rotation(-r.powf(1.01), 1e-2));
+    }
+
+    #[test]
+    fn lerp() {
+        let (a, b) = (Vec3::new(1.0, -2.0, 0.5), Vec3::new(3.0, 0.0, -1.5));
+
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.5), Vec3::new(2.0, -1.0, -0.5));
+        assert_eq!(a.lerp(b, 2.0), 2.0 * b - a);
+        assert!(a.lerp(b, 1.5).is_close(b + 0.5 * (b - a)));
+        assert_eq!(a.lerp_unclamped(b, -1.0), a.lerp(b, -1.0));
+    }
+
+    #[test]
+    fn move_towards() {
+        let target = Vec3::new(3.0, 4.0, 0.0);
+        let mut v = Vec3::ZERO;
+
+        for i in 1..=6 {
+            v = v.move_towards(target, 1.0);
+            assert!((v.length() - f64::min(i as f64, 5.0)).abs() < EPS);
+            assert!((target - v).dot(target) >= 0.0);
+        }
+        assert_eq!(v, target);
+        assert_eq!(Vec3::new(2.9, 4.0, 0.0).move_towards(target, 1.0), target);
+    }
+
+    #[test]
+    fn normalize_or_zero() {
+        let v = Vec3::new(0.0, 3.0, -4.0);
+
+        assert!(v.normalize_or_zero().is_close(Vec3::new(0.0, 0.6, -0.8)));
+        assert_eq!(v.normalize(), v.normalized());
+        assert!(v.normalize().is_normalized());
+        assert!(v.normalize_or_zero().is_normalized());
+        assert!(!v.is_normalized());
+        assert_eq!(Vec3::ZERO.normalize_or_zero(), Vec3::ZERO);
+        assert_eq!(Vec3::new(1e-9, -1e-9, 0.0).normalize_or_zero(), Vec3::ZERO);
+        assert!(!Vec3::ZERO.is_normalized());
+    }
+
+    #[test]
+    fn interpolate() {
+        let axis = Vec3::new(1.0, 1.0, -1.0);
+        let a = Rotor3::from_axis_angle(axis, 0.2);
+        let near = Rotor3::from_axis_angle(axis, 0.205);
+        let far = Rotor3::from_axis_angle(Vec3::unit(1), 2.5);
+
+        assert_eq!(a.interpolate(near, 0.3), a.nlerp(near, 0.3));
+        assert_eq!(a.interpolate(-near, 0.3), a.nlerp(-near, 0.3));
+        assert_eq!(a.interpolate(far, 0.3), a.slerp(far, 0.3));
+        assert!(!a.nlerp(far, 0.3).is_close(a.slerp(far, 0.3)));
+    }
+
+    #[test]
+    fn distance() {
+        let a = Vec3::new(1.0, 1.0, 2.0);
+        let b = Vec3::new(4.0, 5.0, 2.0);
+
+        assert_eq!(a.distance(b), 5.0);
+        assert_eq!(b.distance(a), 5.0);
+        assert_eq!(a.distance_squared(b), 25.0);
+        assert_eq!(a.distance(a), 0.0);
+    }
+
+    #[test]
+    fn parallel_perpendicular() {
+        let x = Vec3::unit(0);
+
+        assert!(x.is_parallel(Vec3::new(2.0, 0.0, 0.0), EPS));
+        assert!(x.is_parallel(Vec3::new(-2.0, 1e-9, 0.0), EPS));
+        assert!(!x.is_parallel(Vec3::new(1.0, 1.0, 0.0), EPS));
+        assert!(x.is_perpendicular(Vec3::new(0.0, 3.0, 0.0), EPS));
+        assert!(!x.is_perpendicular(Vec3::new(0.1, 3.0, 0.0), EPS));
+        assert!(Vec3::new(0.0, 1e3, 1e3).is_perpendicular(Vec3::new(1e-3, 1e3, -1e3), EPS));
+        assert!(x.is_parallel(Vec3::ZERO, EPS) && x.is_perpendicular(Vec3::ZERO, EPS));
+    }
+
+    #[test]
+    fn index() {
+        let mut v = Vec3::new(1.0, 2.0, 3.0);
+
+        assert_eq!((v[0], v[1], v[2]), (1.0, 2.0, 3.0));
+        for i in 0..3 {
+            v[i] *= -2.0;
+        }
+        assert_eq!(v, Vec3::new(-2.0, -4.0, -6.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Vec3 index out of range: 3")]
+    fn index_out_of_range() {
+        let _ = Vec3::ZERO[3];
+    }
+
+    #[test]
+    fn from_forward_up() {
+        let (x, y, z) = (Vec3::unit(0), Vec3::unit(1), Vec3::unit(2));
+
+        assert!(Rotor3::from_forward_up(z, y).is_close(Rotor3::IDENTITY));
+
+        let forward = Vec3::new(1.0, 0.5, -2.0);
+        let up = Vec3::new(0.1, 1.0, 0.2);
+        let r = Rotor3::from_forward_up(forward, up);
+        assert!(r.rotate(z).is_close(forward.normalized()));
+        assert!(r.rotate(y).is_close(up.reject_from(forward).normalized()));
+        assert!((r.magnitude() - 1.0).abs() < EPS);
+
+        let r = Rotor3::from_forward_up(2.0 * x, x);
+        assert!(r.rotate(z).is_close(x));
+        assert!(r.rotate(y).is_perpendicular(x, EPS));
+    }
+
+    #[test]
+    fn twist() {
+        let axis = Vec3::new(0.0, 1.0, 1.0);
+        let swing = Rotor3::from_axis_angle(Vec3::new(1.0, 1.0, -1.0), 0.6);
+        let r = swing * Rotor3::from_axis_angle(axis, -1.2);
+
+        assert!(r.twist(axis).is_close(Rotor3::from_axis_angle(axis, -1.2)));
+        assert!((r.twist_angle(axis) + 1.2).abs() < EPS);
+        assert!((r.twist_angle(-axis) - 1.2).abs() < EPS);
+        assert!(swing.twist_angle(axis).abs() < EPS);
+        let half_turn = Rotor3::from_axis_angle(Vec3::unit(0), PI);
+        assert_eq!(half_turn.twist(axis), Rotor3::IDENTITY);
+    }
+
+    #[test]
+    fn relative_twist() {
+        let axis = Vec3::new(1.0, 0.0, -1.0);
+        let a = Rotor3::from_axis_angle(Vec3::new(0.2, 1.0, 0.5), 0.9);
+
+        let twisted = Rotor3::from_axis_angle(axis, 0.7) * a;
+        assert!((Rotor3::relative_twist(a, twisted, axis) - 0.7).abs() < EPS);
+        let swung = Rotor3::from_axis_angle(Vec3::new(1.0, 2.0, 1.0), 0.4) * a;
+        assert!(Rotor3::relative_twist(a, swung, axis).abs() < EPS);
+        assert!(Rotor3::relative_twist(a, a, axis).abs() < EPS);
+    }
+
+    #[test]
+    fn constrain_to_hinge() {
+        let z = Vec3::unit(2);
+        let target = Rotor3::from_axis_angle(Vec3::unit(0), 0.3) * Rotor3::from_axis_angle(z, 1.0);
+
+        let inside = target.constrain_to_hinge(z, 0.0, 1.5);
+        assert!(inside.is_close(Rotor3::from_axis_angle(z, 1.0)));
+        let clamped = target.constrain_to_hinge(z, -0.5, 0.5);
+        assert!(clamped.is_close(Rotor3::from_axis_angle(z, 0.5)));
+        let clamped = target.constrain_to_hinge(z, 1.2, 2.0);
+        assert!(clamped.is_close(Rotor3::from_axis_angle(z, 1.2)));
+    }
+
+    #[test]
+    fn remove_roll() {
+        let (x, z) = (Vec3::unit(0), Vec3::unit(2));
+        let r = Rotor3::from_euler(0.5, 0.3, 0.8);
+
+        let level = r.remove_roll(x, z);
+        assert!(level.rotate(x).is_close(r.rotate(x)));
+        let (yaw, pitch, roll) = level.to_euler();
+        assert!(Vec3::new(yaw, pitch, roll).is_close(Vec3::new(0.5, 0.3, 0.0)));
+        // What was removed is a twist about the forward axis
+        assert!((level.reverse() * r).rotate(x).is_close(x));
+        assert!(
+            Rotor3::from_euler(-2.0, 0.1, 0.0)
+                .remove_roll(x, z)
+                .is_close_rotation(Rotor3::from_euler(-2.0, 0.1, 0.0), EPS)
+        );
+    }
+
+    #[test]
+    fn array_conversions() {
+        let v = Vec3::new(1.0, -2.0, 3.0);
+
+        assert_eq!(v.to_array(), [1.0, -2.0, 3.0]);
+        assert_eq!(Vec3::from(<[f64; 3]>::from(v)), v);
+        assert_eq!(Vec3::from_slice(&[1.0, -2.0, 3.0, 4.0]), v);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vec3::from_slice needs 3 elements, got 2")]
+    fn from_short_slice() {
+        Vec3::from_slice(&[1.0, 2.0]);
+    }
+
+    #[test]
+    fn classify_against_plane() {
+        let normal = Vec3::new(0.0, 0.0, 2.0);
+
+        assert_eq!(
+            Vec3::new(1.0, 2.0, 3.0).classify_against_plane(normal),
+            (Ordering::Greater, 3.0)
+        );
+        assert_eq!(
+            Vec3::new(5.0, -1.0, 0.0).classify_against_plane(normal).0,
+            Ordering::Equal
+        );
+        assert_eq!(
+            Vec3::new(0.0, 0.0, 1e-9).classify_against_plane(normal).0,
+            Ordering::Equal
+        );
+        assert_eq!(
+            Vec3::new(1.0, 1.0, -0.5).classify_against_plane(normal),
+            (Ordering::Less, -0.5)
+        );
+    }
+
+    #[test]
+    fn assign_operators() {
+        let a = Vec3::new(1.0, -2.0, 3.0);
+        let b = Vec3::new(0.5, 4.0, -1.0);
+
+        let mut v = a;
+        v += b;
+        assert_eq!(v, a + b);
+        v -= b;
+        assert_eq!(v, a + b - b);
+        v *= 3.0;
+        assert_eq!(v, (a + b - b) * 3.0);
+        v /= 2.0;
+        assert_eq!(v, (a + b - b) * 3.0 / 2.0);
+    }
+
+    #[test]
+    fn repeated() {
+        let r = Rotor3::from_axis_angle(Vec3::new(1.0, 2.0, -1.0), 0.3);
+
+        assert_eq!(
+            r.repeated(4),
+            (Rotor3::IDENTITY * r * r * r * r).normalize()
+        );
+        assert!(r.repeated(4).is_close(r.powf(4.0)));
+        assert_eq!(r.repeated(1), r.normalize());
+        assert_eq!(r.repeated(0), Rotor3::IDENTITY);
+    }
+
+    #[test]
+    fn sample_polyline() {
+        let path = [
+            Vec3::ZERO,
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 3.0, 0.0),
+        ];
+
+        assert_eq!(Vec3::sample_polyline(&path, 0.0), path[0]);
+        assert_eq!(Vec3::sample_polyline(&path, 1.0), path[2]);
+        assert_eq!(Vec3::sample_polyline(&path, 0.5), path[1]);
+        assert_eq!(Vec3::sample_polyline(&path, 0.75), Vec3::new(1.0, 1.5, 0.0));
+        assert_eq!(Vec3::sample_polyline(&path, -1.0), path[0]);
+        assert_eq!(Vec3::sample_polyline(&path, 2.0), path[2]);
+        assert_eq!(Vec3::sample_polyline(&path[..1], 0.3), path[0]);
+        assert_eq!(Vec3::sample_polyline(&[], 0.3), Vec3::ZERO);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Vec3::new(1.0, 2.0, 3.0).to_string(), "1 e1 + 2 e2 + 3 e3");
+        assert_eq!(Vec3::new(0.0, -2.0, 0.5).to_string(), "-2 e2 + 0.5 e3");
+        assert_eq!(Vec3::ZERO.to_string(), "0");
+        assert_eq!(BiVec3::new(1.5, 0.0, -1.0).to_string(), "1.5 e12 - 1 e31");
+        assert_eq!(TriVec3::new(2.0).to_string(), "2 e123");
+        assert_eq!(
+            Rotor3::new(0.7, BiVec3::new(0.7, 0.0, 0.0)).to_string(),
+            "0.7 + 0.7 e12"
+        );
+        assert_eq!(
+            format!("{:.2}", Rotor3::from_axis_angle(Vec3::unit(2), FRAC_PI_2)),
+            "0.71 - 0.71 e12"
+        );
+        assert_eq!(
+            MultiVec3::new(-1.0, Vec3::unit(0), BiVec3::ZERO, TriVec3::new(4.0)).to_string(),
+            "-1 + 1 e1 + 4 e123"
+        );
+        assert_eq!(
+            DualRotor3::new(Vec3::new(0.0, 1.0, 0.0), TriVec3::new(-0.5)).to_string(),
+            "1 e2 - 0.5 e123"
+        );
+
+        let v = Vec3::new(0.04, -0.04, 1.0);
+        assert_eq!(format!("{v:.1}"), "1.0 e3");
+        assert_eq!(format!("{:.1}", Vec3::new(0.04, 0.0, 0.0)), "0");
+        assert_eq!(format!("{:>10}", Vec3::unit(0)), "      1 e1");
+        assert_eq!(format!("{:*^8}", TriVec3::new(2.0)), "*2 e123*");
+        assert_eq!(format!("{:<6}|", Vec3::ZERO), "0     |");
+    }
 }